@@ -0,0 +1,21 @@
+//! Compares parsing into an owned `ClassFile` against borrowing Utf8 entries with `ClassFileRef`.
+//!
+//! Run with `cargo bench --bench borrowed`.
+
+use std::hint::black_box;
+
+use rusty_classfile::{ClassFile, ClassFileRef};
+
+mod common;
+
+const HELLO: &[u8] = include_bytes!("../tests/fixtures/Hello.class");
+const ITERATIONS: u32 = 100_000;
+
+fn main() {
+    common::time("ClassFile::from_bytes", ITERATIONS, || {
+        black_box(ClassFile::from_bytes(black_box(HELLO)).unwrap());
+    });
+    common::time("ClassFileRef::from_bytes", ITERATIONS, || {
+        black_box(ClassFileRef::from_bytes(black_box(HELLO)).unwrap());
+    });
+}
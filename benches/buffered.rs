@@ -0,0 +1,40 @@
+//! Compares `read_from`, which wraps its input in a `BufReader`, against `read_from_buffered` on a
+//! slice, which reads from it directly.
+//!
+//! Run with `cargo bench --bench buffered`.
+
+use std::hint::black_box;
+
+use rusty_classfile::{read_from, read_from_buffered};
+
+mod common;
+
+const ITERATIONS: u32 = 1_000;
+
+/// Build a class whose constant pool holds `count` distinct Utf8 entries, with an empty body.
+fn large_class(count: u16) -> Vec<u8> {
+    let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 61];
+    bytes.extend_from_slice(&(count + 1).to_be_bytes());
+    for i in 0..count {
+        let value = format!("com/example/generated/Name{i}");
+        bytes.push(1);
+        bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(value.as_bytes());
+    }
+    // access_flags, this_class, super_class and empty interfaces, fields, methods and attributes.
+    bytes.extend_from_slice(&[0; 14]);
+
+    bytes
+}
+
+fn main() {
+    let class = large_class(u16::MAX - 1);
+    println!("class size: {} bytes", class.len());
+
+    common::time("read_from", ITERATIONS, || {
+        black_box(read_from(black_box(class.as_slice())).unwrap());
+    });
+    common::time("read_from_buffered", ITERATIONS, || {
+        black_box(read_from_buffered(black_box(class.as_slice())).unwrap());
+    });
+}
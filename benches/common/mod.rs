@@ -0,0 +1,22 @@
+//! The timing loop shared by the benchmarks, which have no harness of their own.
+
+use std::time::Instant;
+
+/// Run `parse` `iterations` times, after a warm-up of a tenth as many, and print the average time
+/// it took under `name`.
+pub fn time<F>(name: &str, iterations: u32, mut parse: F)
+    where F: FnMut(),
+{
+    // Warm up caches and the allocator before measuring.
+    for _ in 0..iterations / 10 {
+        parse();
+    }
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        parse();
+    }
+    let elapsed = start.elapsed();
+
+    println!("{name:<24} {:>10?} per class", elapsed / iterations);
+}
@@ -0,0 +1,21 @@
+//! Times parsing a class with a large constant pool, dominated by the constant pool read loop.
+//!
+//! Run with `cargo bench --bench constant_pool`.
+
+use std::hint::black_box;
+
+use rusty_classfile::ClassFile;
+
+mod common;
+
+/// 2000 `static final String` fields, for a pool of around 6000 entries.
+const LARGE: &[u8] = include_bytes!("../tests/fixtures/Large.class");
+const ITERATIONS: u32 = 1_000;
+
+fn main() {
+    println!("class size: {} bytes", LARGE.len());
+
+    common::time("ClassFile::from_bytes", ITERATIONS, || {
+        black_box(ClassFile::from_bytes(black_box(LARGE)).unwrap());
+    });
+}
@@ -0,0 +1,149 @@
+//! Typed wrappers around the `access_flags` masks of classes, fields and methods.
+
+access_flags! {
+    /// The `access_flags` of a class or interface.
+    ClassAccessFlags, {
+        PUBLIC = 0x0001 => is_public,
+        FINAL = 0x0010 => is_final,
+        SUPER = 0x0020 => is_super,
+        INTERFACE = 0x0200 => is_interface,
+        ABSTRACT = 0x0400 => is_abstract,
+        SYNTHETIC = 0x1000 => is_synthetic,
+        ANNOTATION = 0x2000 => is_annotation,
+        ENUM = 0x4000 => is_enum,
+        MODULE = 0x8000 => is_module,
+    }
+}
+
+access_flags! {
+    /// The `access_flags` of a field.
+    FieldAccessFlags, {
+        PUBLIC = 0x0001 => is_public,
+        PRIVATE = 0x0002 => is_private,
+        PROTECTED = 0x0004 => is_protected,
+        STATIC = 0x0008 => is_static,
+        FINAL = 0x0010 => is_final,
+        VOLATILE = 0x0040 => is_volatile,
+        TRANSIENT = 0x0080 => is_transient,
+        SYNTHETIC = 0x1000 => is_synthetic,
+        ENUM = 0x4000 => is_enum,
+    }
+}
+
+access_flags! {
+    /// The `access_flags` of a method.
+    MethodAccessFlags, {
+        PUBLIC = 0x0001 => is_public,
+        PRIVATE = 0x0002 => is_private,
+        PROTECTED = 0x0004 => is_protected,
+        STATIC = 0x0008 => is_static,
+        FINAL = 0x0010 => is_final,
+        SYNCHRONIZED = 0x0020 => is_synchronized,
+        BRIDGE = 0x0040 => is_bridge,
+        VARARGS = 0x0080 => is_varargs,
+        NATIVE = 0x0100 => is_native,
+        ABSTRACT = 0x0400 => is_abstract,
+        STRICT = 0x0800 => is_strict,
+        SYNTHETIC = 0x1000 => is_synthetic,
+    }
+}
+
+access_flags! {
+    /// The `inner_class_access_flags` of an `InnerClasses` entry, as the nested class was
+    /// declared in source.
+    InnerClassAccessFlags, {
+        PUBLIC = 0x0001 => is_public,
+        PRIVATE = 0x0002 => is_private,
+        PROTECTED = 0x0004 => is_protected,
+        STATIC = 0x0008 => is_static,
+        FINAL = 0x0010 => is_final,
+        INTERFACE = 0x0200 => is_interface,
+        ABSTRACT = 0x0400 => is_abstract,
+        SYNTHETIC = 0x1000 => is_synthetic,
+        ANNOTATION = 0x2000 => is_annotation,
+        ENUM = 0x4000 => is_enum,
+    }
+}
+
+access_flags! {
+    /// The `access_flags` of a `MethodParameters` entry. `MANDATED` marks a parameter that the
+    /// language requires but the source doesn't spell out, like the outer instance passed to an
+    /// inner class's constructor.
+    ParameterAccessFlags, {
+        FINAL = 0x0010 => is_final,
+        SYNTHETIC = 0x1000 => is_synthetic,
+        MANDATED = 0x8000 => is_mandated,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
+
+    #[test]
+    fn test_interface_flags() {
+        let flags = ClassAccessFlags::from_bits_truncate(0x0601);
+        assert_eq!(flags, ClassAccessFlags::PUBLIC | ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT);
+        assert!(flags.is_public());
+        assert!(flags.is_interface());
+        assert!(flags.is_abstract());
+        assert!(!flags.is_final());
+        assert_eq!(flags.bits(), 0x0601);
+    }
+
+    #[test]
+    fn test_debug() {
+        let flags = ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL;
+        assert_eq!(format!("{flags:?}"), "ClassAccessFlags(PUBLIC | FINAL)");
+    }
+
+    #[test]
+    fn test_names() {
+        let flags = MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC | MethodAccessFlags::VARARGS;
+        assert_eq!(flags.names().collect::<Vec<_>>(), vec!["PUBLIC", "STATIC", "VARARGS"]);
+    }
+
+    #[test]
+    fn test_super_is_not_synchronized() {
+        // 0x0020 is ACC_SUPER on a class but ACC_SYNCHRONIZED on a method.
+        let flags = ClassAccessFlags::from_bits_truncate(0x0021);
+        assert_eq!(flags, ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER);
+        assert_eq!(flags.names().collect::<Vec<_>>(), vec!["PUBLIC", "SUPER"]);
+
+        let flags = MethodAccessFlags::from_bits_truncate(0x0021);
+        assert_eq!(flags.names().collect::<Vec<_>>(), vec!["PUBLIC", "SYNCHRONIZED"]);
+    }
+
+    #[test]
+    fn test_unknown_bits() {
+        // 0x0100 isn't assigned to any class flag.
+        let flags = ClassAccessFlags::from_bits_retain(0x0121);
+        assert_eq!(flags.bits(), 0x0121);
+        assert_eq!(flags.unknown_bits(), 0x0100);
+        assert!(flags.is_public());
+        assert_eq!(format!("{flags:?}"), "ClassAccessFlags(PUBLIC | SUPER | 0x0100)");
+
+        let truncated = ClassAccessFlags::from_bits_truncate(0x0121);
+        assert_eq!(truncated, ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER);
+        assert_eq!(truncated.unknown_bits(), 0);
+    }
+
+    #[test]
+    fn test_private_static_final_field() {
+        let flags = FieldAccessFlags::from_bits_truncate(0x001A);
+        assert_eq!(flags, FieldAccessFlags::PRIVATE | FieldAccessFlags::STATIC | FieldAccessFlags::FINAL);
+        assert!(flags.is_private());
+        assert!(flags.is_static());
+        assert!(flags.is_final());
+        assert!(!flags.is_volatile());
+    }
+
+    #[test]
+    fn test_public_native_method() {
+        let flags = MethodAccessFlags::from_bits_truncate(0x0101);
+        assert_eq!(flags, MethodAccessFlags::PUBLIC | MethodAccessFlags::NATIVE);
+        assert!(flags.is_public());
+        assert!(flags.is_native());
+        assert!(!flags.is_static());
+    }
+}
@@ -0,0 +1,287 @@
+//! The annotation structures shared by the `Runtime*Annotations` and
+//! `Runtime*ParameterAnnotations` families of attributes.
+
+use std::io::BufRead;
+
+use crate::{capacity_for, Error, ReadExt};
+
+/// A single annotation, such as `@Deprecated(since = "9")`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    /// Index of the Utf8 entry holding the annotation type as a field descriptor, e.g.
+    /// `Ljava/lang/Deprecated;`.
+    pub type_index: u16,
+    /// The explicitly given members, as the index of the Utf8 entry naming each one along with
+    /// its value. Members left at their default value don't appear.
+    pub element_value_pairs: Vec<(u16, ElementValue)>,
+}
+
+/// The value of an annotation member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementValue {
+    /// A primitive or string constant. `tag` is one of `B`, `C`, `D`, `F`, `I`, `J`, `S`, `Z` or
+    /// `s`, and `const_value_index` points at the constant pool entry holding the value.
+    Const { tag: u8, const_value_index: u16 },
+    /// An enum constant, given by the Utf8 entries for its type descriptor and its simple name.
+    Enum { type_name_index: u16, const_name_index: u16 },
+    /// A class literal, given by the Utf8 entry for its return descriptor, e.g. `V` for
+    /// `void.class` or `Ljava/lang/Object;` for `Object.class`.
+    Class { class_info_index: u16 },
+    /// A nested annotation.
+    Annotation(Annotation),
+    /// An array of values.
+    Array(Vec<ElementValue>),
+}
+
+/// Read a `num_annotations` count followed by that many annotations, allowing values to nest at
+/// most `max_depth` levels deep.
+pub(crate) fn read_annotations<R>(mut buf_read: R, max_depth: usize) -> Result<Vec<Annotation>, Error>
+    where R: BufRead,
+{
+    let num_annotations = buf_read.read_u16()?;
+    // Each annotation takes at least its type index and pair count.
+    let mut annotations = Vec::with_capacity(capacity_for(&mut buf_read, num_annotations, 4)?);
+    for _ in 0..num_annotations {
+        annotations.push(read_annotation(&mut buf_read, Depth { depth: 0, max_depth })?);
+    }
+
+    Ok(annotations)
+}
+
+/// Read a `num_parameters` count followed by that many lists of annotations, one for each
+/// parameter, as found in the `Runtime*ParameterAnnotations` attributes.
+pub(crate) fn read_parameter_annotations<R>(mut buf_read: R, max_depth: usize) -> Result<Vec<Vec<Annotation>>, Error>
+    where R: BufRead,
+{
+    let num_parameters = buf_read.read_u8()?;
+    let mut parameter_annotations = Vec::with_capacity(capacity_for(&mut buf_read, num_parameters.into(), 2)?);
+    for _ in 0..num_parameters {
+        parameter_annotations.push(read_annotations(&mut buf_read, max_depth)?);
+    }
+
+    Ok(parameter_annotations)
+}
+
+/// How deeply nested the value being read is. Nested annotations and arrays each add a level, and
+/// every level is a stack frame, so the limit keeps corrupt input from overflowing the stack.
+#[derive(Clone, Copy)]
+struct Depth {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Depth {
+    fn nested(self) -> Result<Depth, Error> {
+        if self.depth >= self.max_depth {
+            return Err(Error::AttributeNestingTooDeep { max_depth: self.max_depth });
+        }
+
+        Ok(Depth { depth: self.depth + 1, ..self })
+    }
+}
+
+fn read_annotation<R>(buf_read: &mut R, depth: Depth) -> Result<Annotation, Error>
+    where R: BufRead,
+{
+    let type_index = buf_read.read_u16()?;
+    let num_element_value_pairs = buf_read.read_u16()?;
+    // A pair is a name index and a value, which is at least a tag and an index.
+    let mut element_value_pairs = Vec::with_capacity(capacity_for(&mut *buf_read, num_element_value_pairs, 5)?);
+    for _ in 0..num_element_value_pairs {
+        let element_name_index = buf_read.read_u16()?;
+        element_value_pairs.push((element_name_index, read_element_value(buf_read, depth)?));
+    }
+
+    Ok(Annotation { type_index, element_value_pairs })
+}
+
+fn read_element_value<R>(buf_read: &mut R, depth: Depth) -> Result<ElementValue, Error>
+    where R: BufRead,
+{
+    let tag = buf_read.read_u8()?;
+    let element_value = match tag {
+        b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' | b's' => {
+            ElementValue::Const { tag, const_value_index: buf_read.read_u16()? }
+        }
+        b'e' => ElementValue::Enum {
+            type_name_index: buf_read.read_u16()?,
+            const_name_index: buf_read.read_u16()?,
+        },
+        b'c' => ElementValue::Class { class_info_index: buf_read.read_u16()? },
+        b'@' => ElementValue::Annotation(read_annotation(buf_read, depth.nested()?)?),
+        b'[' => {
+            let depth = depth.nested()?;
+            let num_values = buf_read.read_u16()?;
+            let mut values = Vec::with_capacity(capacity_for(&mut *buf_read, num_values, 3)?);
+            for _ in 0..num_values {
+                values.push(read_element_value(buf_read, depth)?);
+            }
+            ElementValue::Array(values)
+        }
+        _ => return Err(Error::InvalidElementValueTag(tag)),
+    };
+
+    Ok(element_value)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Annotation, AttributeInfo, ClassFile, ConstantPool, ConstantPoolItem, ElementValue, Error, ParseOptions};
+    use crate::annotations::read_annotations;
+
+    #[test]
+    fn test_nested_annotation() {
+        // @Outer(inner = @Inner(c = Object.class))
+        let info: &[u8] = &[0, 1, 0, 1, 0, 1, 0, 2, b'@', 0, 3, 0, 1, 0, 4, b'c', 0, 5];
+        assert_eq!(read_annotations(info, 2).unwrap(), vec![Annotation {
+            type_index: 1,
+            element_value_pairs: vec![(2, ElementValue::Annotation(Annotation {
+                type_index: 3,
+                element_value_pairs: vec![(4, ElementValue::Class { class_info_index: 5 })],
+            }))],
+        }]);
+    }
+
+    #[test]
+    fn test_nesting_too_deep() {
+        // @A(value = [[[...[1]...]]]), with the arrays nested 1000 levels deep.
+        let mut info = vec![0, 1, 0, 1, 0, 1, 0, 2];
+        for _ in 0..1000 {
+            info.extend_from_slice(&[b'[', 0, 1]);
+        }
+        info.extend_from_slice(&[b'I', 0, 3]);
+
+        let err = read_annotations(info.as_slice(), 64).unwrap_err();
+        assert_eq!(err, Error::AttributeNestingTooDeep { max_depth: 64 });
+        assert_eq!(read_annotations(info.as_slice(), 1000).unwrap().len(), 1);
+
+        // The nested annotation in test_nested_annotation is one level deep.
+        let info: &[u8] = &[0, 1, 0, 1, 0, 1, 0, 2, b'@', 0, 3, 0, 1, 0, 4, b'c', 0, 5];
+        assert!(read_annotations(info, 1).is_ok());
+        assert_eq!(read_annotations(info, 0).unwrap_err(), Error::AttributeNestingTooDeep { max_depth: 0 });
+    }
+
+    #[test]
+    fn test_invalid_tag() {
+        let info: &[u8] = &[0, 1, 0, 1, 0, 1, 0, 2, b'?'];
+        assert!(matches!(read_annotations(info, 2).unwrap_err(), Error::InvalidElementValueTag(b'?')));
+    }
+
+    #[test]
+    fn test_single_member_annotation() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Annotated.class")).unwrap();
+        let pool = &class_file.constant_pool;
+
+        let annotations = class_file.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_visible_annotations(pool))
+            .unwrap()
+            .unwrap();
+        assert_eq!(annotations.len(), 1);
+        let version = &annotations[0];
+        assert_eq!(pool.resolve_utf8(version.type_index), Some("LAnnotated$Version;"));
+
+        let [(name_index, ElementValue::Const { tag: b'I', const_value_index })] = version.element_value_pairs[..] else {
+            panic!("unexpected members {:?}", version.element_value_pairs);
+        };
+        assert_eq!(pool.resolve_utf8(name_index), Some("value"));
+        assert_eq!(pool.get(const_value_index), Some(&ConstantPoolItem::Integer(3)));
+    }
+
+    #[test]
+    fn test_parameter_annotations() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/ParameterAnnotated.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let greet = class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some("greet"))
+            .unwrap();
+        let type_names = |annotations: &[Annotation]| -> Vec<&str> {
+            annotations.iter().map(|annotation| pool.resolve_utf8(annotation.type_index).unwrap()).collect()
+        };
+
+        let visible = greet.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_visible_parameter_annotations(pool))
+            .unwrap()
+            .unwrap();
+        assert_eq!(visible.len(), 3);
+        assert_eq!(type_names(&visible[0]), vec!["LParameterAnnotated$Named;"]);
+        assert!(visible[1].is_empty() && visible[2].is_empty());
+        let [(_, ElementValue::Const { tag: b's', const_value_index })] = visible[0][0].element_value_pairs[..] else {
+            panic!("unexpected members {:?}", visible[0][0].element_value_pairs);
+        };
+        assert_eq!(pool.resolve_utf8(const_value_index), Some("who"));
+
+        let invisible = greet.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_invisible_parameter_annotations(pool))
+            .unwrap()
+            .unwrap();
+        let invisible: Vec<_> = invisible.iter().map(|annotations| type_names(annotations)).collect();
+        assert_eq!(invisible, vec![vec![], vec![], vec!["LParameterAnnotated$Checked;"]]);
+
+        let method_annotations = greet.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_invisible_annotations(pool))
+            .unwrap()
+            .unwrap();
+        assert_eq!(type_names(&method_annotations), vec!["LParameterAnnotated$Checked;"]);
+        assert!(greet.attributes.iter().all(|attribute| attribute.as_runtime_visible_annotations(pool).is_none()));
+    }
+
+    #[test]
+    fn test_array_member() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Annotated.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let run = class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some("run"))
+            .unwrap();
+
+        let annotations = run.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_visible_annotations(pool))
+            .unwrap()
+            .unwrap();
+        let (_, ElementValue::Array(values)) = &annotations[0].element_value_pairs[0] else {
+            panic!("expected an array member");
+        };
+        let strings: Vec<&str> = values.iter()
+            .map(|value| match value {
+                ElementValue::Const { tag: b's', const_value_index } => pool.resolve_utf8(*const_value_index).unwrap(),
+                other => panic!("expected a string constant, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(strings, vec!["fast", "safe"]);
+
+        let options = ParseOptions { max_attribute_depth: 0, ..ParseOptions::default() };
+        let err = run.attributes.iter()
+            .find_map(|attribute| attribute.as_runtime_visible_annotations_with_options(pool, options))
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err, Error::AttributeNestingTooDeep { max_depth: 0 });
+    }
+
+    #[test]
+    fn test_invisible_and_parameter_annotations_with_options() {
+        let pool = ConstantPool::from(vec![
+            ConstantPoolItem::Utf8("RuntimeInvisibleAnnotations".to_string()),
+            ConstantPoolItem::Utf8("RuntimeVisibleParameterAnnotations".to_string()),
+            ConstantPoolItem::Utf8("RuntimeInvisibleParameterAnnotations".to_string()),
+        ]);
+        // @A(value = [1]), so one level of nesting. The parameter attributes hold it for a single
+        // parameter.
+        let annotations = vec![0, 1, 0, 1, 0, 1, 0, 2, b'[', 0, 1, b'I', 0, 3];
+        let parameter_annotations = [&[1], annotations.as_slice()].concat();
+        let options = ParseOptions { max_attribute_depth: 0, ..ParseOptions::default() };
+
+        let invisible = AttributeInfo { name_index: 1, info: annotations };
+        assert_eq!(invisible.as_runtime_invisible_annotations(&pool).unwrap().unwrap().len(), 1);
+        let result = invisible.as_runtime_invisible_annotations_with_options(&pool, options).unwrap();
+        assert_eq!(result, Err(Error::AttributeNestingTooDeep { max_depth: 0 }));
+
+        let visible = AttributeInfo { name_index: 2, info: parameter_annotations.clone() };
+        assert_eq!(visible.as_runtime_visible_parameter_annotations(&pool).unwrap().unwrap().len(), 1);
+        let result = visible.as_runtime_visible_parameter_annotations_with_options(&pool, options).unwrap();
+        assert_eq!(result, Err(Error::AttributeNestingTooDeep { max_depth: 0 }));
+
+        let invisible = AttributeInfo { name_index: 3, info: parameter_annotations };
+        assert_eq!(invisible.as_runtime_invisible_parameter_annotations(&pool).unwrap().unwrap().len(), 1);
+        let result = invisible.as_runtime_invisible_parameter_annotations_with_options(&pool, options).unwrap();
+        assert_eq!(result, Err(Error::AttributeNestingTooDeep { max_depth: 0 }));
+    }
+}
@@ -0,0 +1,657 @@
+//! Attributes attached to classes, fields, methods and `Code` bodies.
+//!
+//! Attributes are always read generically into an [`AttributeInfo`], and can then be parsed into
+//! one of the typed forms on demand.
+
+use std::io::BufRead;
+
+use crate::{Annotation, capacity_for, ConstantPool, Error, InnerClassAccessFlags, ParameterAccessFlags, ParseOptions, read_bytes, ReadExt, StackMapFrame};
+use crate::annotations::{read_annotations, read_parameter_annotations};
+use crate::stack_map::read_stack_map_table;
+
+/// A generic `attribute_info` entry, with its contents left as raw bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeInfo {
+    pub name_index: u16,
+    pub info: Vec<u8>,
+}
+
+impl AttributeInfo {
+    /// Resolve the attribute's name, e.g. `"Code"`, against the constant pool.
+    pub fn name<'a>(&self, constant_pool: &'a ConstantPool) -> Option<&'a str> {
+        constant_pool.resolve_utf8(self.name_index)
+    }
+
+    /// The attribute's contents exactly as they appeared in the class file, after the length. The
+    /// typed `as_*` parsers only read from these, so they stay available after parsing.
+    pub fn raw(&self) -> &[u8] {
+        &self.info
+    }
+
+    /// Parse this attribute as a `Code` attribute. Returns `None` if it is some other attribute.
+    pub fn as_code(&self, constant_pool: &ConstantPool) -> Option<Result<CodeAttribute, Error>> {
+        self.parse_as(constant_pool, "Code", CodeAttribute::read)
+    }
+
+    /// Parse this attribute as a `SourceFile` attribute, returning the index of the Utf8 entry
+    /// holding the source file name. Returns `None` if it is some other attribute.
+    pub fn as_source_file(&self, constant_pool: &ConstantPool) -> Option<Result<u16, Error>> {
+        self.parse_as(constant_pool, "SourceFile", |mut info| Ok(info.read_u16()?))
+    }
+
+    /// Parse this attribute as a `ConstantValue` attribute, returning the index of the constant
+    /// pool entry holding the field's value. Returns `None` if it is some other attribute.
+    pub fn as_constant_value(&self, constant_pool: &ConstantPool) -> Option<Result<u16, Error>> {
+        self.parse_as(constant_pool, "ConstantValue", |mut info| Ok(info.read_u16()?))
+    }
+
+    /// Parse this attribute as a `BootstrapMethods` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_bootstrap_methods(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<BootstrapMethod>, Error>> {
+        self.parse_as(constant_pool, "BootstrapMethods", |mut info| {
+            let num_bootstrap_methods = info.read_u16()?;
+            // Each method takes at least its method_ref and argument count.
+            let mut bootstrap_methods = Vec::with_capacity(capacity_for(info, num_bootstrap_methods, 4)?);
+            for _ in 0..num_bootstrap_methods {
+                let method_ref = info.read_u16()?;
+                let num_bootstrap_arguments = info.read_u16()?;
+                let mut arguments = Vec::with_capacity(capacity_for(info, num_bootstrap_arguments, 2)?);
+                for _ in 0..num_bootstrap_arguments {
+                    arguments.push(info.read_u16()?);
+                }
+
+                bootstrap_methods.push(BootstrapMethod { method_ref, arguments });
+            }
+
+            Ok(bootstrap_methods)
+        })
+    }
+
+    /// Parse this attribute as a `LineNumberTable` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_line_number_table(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<LineNumber>, Error>> {
+        self.parse_as(constant_pool, "LineNumberTable", |mut info| {
+            let line_number_table_length = info.read_u16()?;
+            let mut line_numbers = Vec::with_capacity(capacity_for(info, line_number_table_length, 4)?);
+            for _ in 0..line_number_table_length {
+                line_numbers.push(LineNumber {
+                    start_pc: info.read_u16()?,
+                    line_number: info.read_u16()?,
+                });
+            }
+
+            Ok(line_numbers)
+        })
+    }
+
+    /// Parse this attribute as a `PermittedSubclasses` attribute, returning the indices of the
+    /// Class entries for the classes allowed to extend a sealed class. Returns `None` if it is
+    /// some other attribute.
+    pub fn as_permitted_subclasses(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<u16>, Error>> {
+        self.parse_as(constant_pool, "PermittedSubclasses", read_indices)
+    }
+
+    /// Parse this attribute as a `LocalVariableTable` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_local_variable_table(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<LocalVariable>, Error>> {
+        self.parse_as(constant_pool, "LocalVariableTable", |mut info| {
+            let local_variable_table_length = info.read_u16()?;
+            let mut local_variables = Vec::with_capacity(capacity_for(info, local_variable_table_length, 10)?);
+            for _ in 0..local_variable_table_length {
+                local_variables.push(LocalVariable {
+                    start_pc: info.read_u16()?,
+                    length: info.read_u16()?,
+                    name_index: info.read_u16()?,
+                    descriptor_index: info.read_u16()?,
+                    index: info.read_u16()?,
+                });
+            }
+
+            Ok(local_variables)
+        })
+    }
+
+    /// Parse this attribute as a `MethodParameters` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_method_parameters(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<MethodParameter>, Error>> {
+        self.parse_as(constant_pool, "MethodParameters", |mut info| {
+            let parameters_count = info.read_u8()?;
+            let mut parameters = Vec::with_capacity(capacity_for(info, parameters_count.into(), 4)?);
+            for _ in 0..parameters_count {
+                parameters.push(MethodParameter {
+                    name_index: info.read_u16()?,
+                    access_flags: ParameterAccessFlags::from_bits_retain(info.read_u16()?),
+                });
+            }
+
+            Ok(parameters)
+        })
+    }
+
+    /// Parse this attribute as an `Exceptions` attribute, returning the indices of the Class
+    /// entries for each checked exception a method declares. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_exceptions(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<u16>, Error>> {
+        self.parse_as(constant_pool, "Exceptions", read_indices)
+    }
+
+    /// Parse this attribute as a `NestHost` attribute, returning the index of the Class entry for
+    /// the class whose nest this one belongs to. Returns `None` if it is some other attribute.
+    pub fn as_nest_host(&self, constant_pool: &ConstantPool) -> Option<Result<u16, Error>> {
+        self.parse_as(constant_pool, "NestHost", |mut info| Ok(info.read_u16()?))
+    }
+
+    /// Parse this attribute as a `NestMembers` attribute, returning the indices of the Class
+    /// entries for the other members of the nest this class hosts. Returns `None` if it is some
+    /// other attribute.
+    pub fn as_nest_members(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<u16>, Error>> {
+        self.parse_as(constant_pool, "NestMembers", read_indices)
+    }
+
+    /// Parse this attribute as an `InnerClasses` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_inner_classes(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<InnerClassEntry>, Error>> {
+        self.parse_as(constant_pool, "InnerClasses", |mut info| {
+            let number_of_classes = info.read_u16()?;
+            let mut inner_classes = Vec::with_capacity(capacity_for(info, number_of_classes, 8)?);
+            for _ in 0..number_of_classes {
+                inner_classes.push(InnerClassEntry {
+                    inner_class_info_index: info.read_u16()?,
+                    outer_class_info_index: info.read_u16()?,
+                    inner_name_index: info.read_u16()?,
+                    inner_class_access_flags: InnerClassAccessFlags::from_bits_retain(info.read_u16()?),
+                });
+            }
+
+            Ok(inner_classes)
+        })
+    }
+
+    /// Parse this attribute as an `EnclosingMethod` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_enclosing_method(&self, constant_pool: &ConstantPool) -> Option<Result<EnclosingMethod, Error>> {
+        self.parse_as(constant_pool, "EnclosingMethod", |mut info| {
+            Ok(EnclosingMethod {
+                class_index: info.read_u16()?,
+                method_index: info.read_u16()?,
+            })
+        })
+    }
+
+    /// Parse this attribute as a `RuntimeVisibleAnnotations` attribute. Returns `None` if it is
+    /// some other attribute.
+    pub fn as_runtime_visible_annotations(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<Annotation>, Error>> {
+        self.as_runtime_visible_annotations_with_options(constant_pool, ParseOptions::default())
+    }
+
+    /// Like [`as_runtime_visible_annotations`](Self::as_runtime_visible_annotations), but with the
+    /// nesting limit taken from `options.max_attribute_depth`.
+    pub fn as_runtime_visible_annotations_with_options(
+        &self,
+        constant_pool: &ConstantPool,
+        options: ParseOptions,
+    ) -> Option<Result<Vec<Annotation>, Error>> {
+        self.parse_as(constant_pool, "RuntimeVisibleAnnotations", |info| {
+            read_annotations(info, options.max_attribute_depth)
+        })
+    }
+
+    /// Parse this attribute as a `RuntimeInvisibleAnnotations` attribute, which holds the
+    /// annotations whose retention is `CLASS`. Returns `None` if it is some other attribute.
+    pub fn as_runtime_invisible_annotations(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<Annotation>, Error>> {
+        self.as_runtime_invisible_annotations_with_options(constant_pool, ParseOptions::default())
+    }
+
+    /// Like [`as_runtime_invisible_annotations`](Self::as_runtime_invisible_annotations), but with
+    /// the nesting limit taken from `options.max_attribute_depth`.
+    pub fn as_runtime_invisible_annotations_with_options(
+        &self,
+        constant_pool: &ConstantPool,
+        options: ParseOptions,
+    ) -> Option<Result<Vec<Annotation>, Error>> {
+        self.parse_as(constant_pool, "RuntimeInvisibleAnnotations", |info| {
+            read_annotations(info, options.max_attribute_depth)
+        })
+    }
+
+    /// Parse this attribute as a `RuntimeVisibleParameterAnnotations` attribute, returning the
+    /// annotations on each of a method's parameters, in order. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_runtime_visible_parameter_annotations(
+        &self,
+        constant_pool: &ConstantPool,
+    ) -> Option<Result<Vec<Vec<Annotation>>, Error>> {
+        self.as_runtime_visible_parameter_annotations_with_options(constant_pool, ParseOptions::default())
+    }
+
+    /// Like [`as_runtime_visible_parameter_annotations`](Self::as_runtime_visible_parameter_annotations),
+    /// but with the nesting limit taken from `options.max_attribute_depth`.
+    pub fn as_runtime_visible_parameter_annotations_with_options(
+        &self,
+        constant_pool: &ConstantPool,
+        options: ParseOptions,
+    ) -> Option<Result<Vec<Vec<Annotation>>, Error>> {
+        self.parse_as(constant_pool, "RuntimeVisibleParameterAnnotations", |info| {
+            read_parameter_annotations(info, options.max_attribute_depth)
+        })
+    }
+
+    /// Parse this attribute as a `RuntimeInvisibleParameterAnnotations` attribute. Returns `None`
+    /// if it is some other attribute.
+    pub fn as_runtime_invisible_parameter_annotations(
+        &self,
+        constant_pool: &ConstantPool,
+    ) -> Option<Result<Vec<Vec<Annotation>>, Error>> {
+        self.as_runtime_invisible_parameter_annotations_with_options(constant_pool, ParseOptions::default())
+    }
+
+    /// Like [`as_runtime_invisible_parameter_annotations`](Self::as_runtime_invisible_parameter_annotations),
+    /// but with the nesting limit taken from `options.max_attribute_depth`.
+    pub fn as_runtime_invisible_parameter_annotations_with_options(
+        &self,
+        constant_pool: &ConstantPool,
+        options: ParseOptions,
+    ) -> Option<Result<Vec<Vec<Annotation>>, Error>> {
+        self.parse_as(constant_pool, "RuntimeInvisibleParameterAnnotations", |info| {
+            read_parameter_annotations(info, options.max_attribute_depth)
+        })
+    }
+
+    /// Parse this attribute as a `StackMapTable` attribute. Returns `None` if it is some other
+    /// attribute.
+    pub fn as_stack_map_table(&self, constant_pool: &ConstantPool) -> Option<Result<Vec<StackMapFrame>, Error>> {
+        self.parse_as(constant_pool, "StackMapTable", read_stack_map_table)
+    }
+
+    /// Run `parse` over the attribute's contents if the attribute is called `name`.
+    fn parse_as<'a, T, F>(&'a self, constant_pool: &ConstantPool, name: &str, parse: F) -> Option<Result<T, Error>>
+        where F: FnOnce(&'a [u8]) -> Result<T, Error>,
+    {
+        if self.name(constant_pool)? != name {
+            return None;
+        }
+
+        Some(parse(self.info.as_slice()))
+    }
+}
+
+/// The `Code` attribute of a method, holding its bytecode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeAttribute {
+    pub max_stack: u16,
+    pub max_locals: u16,
+    pub code: Vec<u8>,
+    pub exception_table: Vec<ExceptionTableEntry>,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+/// One entry of a `Code` attribute's exception table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExceptionTableEntry {
+    pub start_pc: u16,
+    pub end_pc: u16,
+    pub handler_pc: u16,
+    /// Index of the Class entry for the caught exception type, or 0 to catch everything.
+    pub catch_type: u16,
+}
+
+/// One entry of a `BootstrapMethods` attribute, referenced by index from `Dynamic` and
+/// `InvokeDynamic` constant pool entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootstrapMethod {
+    /// Index of the MethodHandle entry for the bootstrap method.
+    pub method_ref: u16,
+    /// Indices of the constant pool entries passed as static arguments to the bootstrap method.
+    pub arguments: Vec<u16>,
+}
+
+/// One entry of a `LineNumberTable` attribute, marking where a source line's bytecode begins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineNumber {
+    pub start_pc: u16,
+    pub line_number: u16,
+}
+
+/// One entry of a `LocalVariableTable` attribute, naming a local variable over the range of
+/// bytecode where it holds a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalVariable {
+    pub start_pc: u16,
+    /// The number of bytes of bytecode, starting at `start_pc`, that the variable is live for.
+    pub length: u16,
+    pub name_index: u16,
+    /// Index of the Utf8 entry holding the variable's field descriptor.
+    pub descriptor_index: u16,
+    /// The variable's slot in the local variable array. `Long` and `Double` variables also take
+    /// up the slot after it.
+    pub index: u16,
+}
+
+/// One entry of a `MethodParameters` attribute, describing a formal parameter of a method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MethodParameter {
+    /// Index of the Utf8 entry holding the parameter's name, or 0 if it has none.
+    pub name_index: u16,
+    pub access_flags: ParameterAccessFlags,
+}
+
+/// One entry of an `InnerClasses` attribute, describing a nested class this class refers to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InnerClassEntry {
+    /// Index of the Class entry for the nested class.
+    pub inner_class_info_index: u16,
+    /// Index of the Class entry for the class the nested class is a member of, or 0 if it is a
+    /// local or anonymous class.
+    pub outer_class_info_index: u16,
+    /// Index of the Utf8 entry for the nested class's simple name, or 0 if it is anonymous.
+    pub inner_name_index: u16,
+    pub inner_class_access_flags: InnerClassAccessFlags,
+}
+
+/// The `EnclosingMethod` attribute of a local or anonymous class.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnclosingMethod {
+    /// Index of the Class entry for the innermost class enclosing this one.
+    pub class_index: u16,
+    /// Index of the NameAndType entry for the enclosing method, or 0 if the class isn't declared
+    /// inside a method, e.g. when it's in a field initializer.
+    pub method_index: u16,
+}
+
+impl EnclosingMethod {
+    /// The index of the NameAndType entry for the enclosing method, or `None` if there isn't one.
+    pub fn method(&self) -> Option<u16> {
+        (self.method_index != 0).then_some(self.method_index)
+    }
+}
+
+impl CodeAttribute {
+    /// All entries of this method's `LineNumberTable` attributes. A `Code` attribute may carry any
+    /// number of them, so they're concatenated in the order they appear.
+    pub fn line_numbers(&self, constant_pool: &ConstantPool) -> Result<Vec<LineNumber>, Error> {
+        let mut line_numbers = Vec::new();
+        for attribute in &self.attributes {
+            if let Some(table) = attribute.as_line_number_table(constant_pool) {
+                line_numbers.extend(table?);
+            }
+        }
+
+        Ok(line_numbers)
+    }
+
+    /// All entries of this method's `LocalVariableTable` attributes, in the order they appear.
+    /// These are only present if the class was compiled with debug information, e.g. `javac -g`.
+    pub fn local_variables(&self, constant_pool: &ConstantPool) -> Result<Vec<LocalVariable>, Error> {
+        let mut local_variables = Vec::new();
+        for attribute in &self.attributes {
+            if let Some(table) = attribute.as_local_variable_table(constant_pool) {
+                local_variables.extend(table?);
+            }
+        }
+
+        Ok(local_variables)
+    }
+
+    /// The frames of this method's `StackMapTable` attribute, or an empty list if it has none,
+    /// as is the case for methods without any branches.
+    pub fn stack_map_table(&self, constant_pool: &ConstantPool) -> Result<Vec<StackMapFrame>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_stack_map_table(constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    fn read<R>(mut buf_read: R) -> Result<CodeAttribute, Error>
+        where R: BufRead,
+    {
+        let max_stack = buf_read.read_u16()?;
+        let max_locals = buf_read.read_u16()?;
+
+        let code_length = buf_read.read_u32()?;
+        let code = read_bytes(&mut buf_read, code_length)?;
+
+        let exception_table_length = buf_read.read_u16()?;
+        let mut exception_table = Vec::with_capacity(capacity_for(&mut buf_read, exception_table_length, 8)?);
+        for _ in 0..exception_table_length {
+            exception_table.push(ExceptionTableEntry {
+                start_pc: buf_read.read_u16()?,
+                end_pc: buf_read.read_u16()?,
+                handler_pc: buf_read.read_u16()?,
+                catch_type: buf_read.read_u16()?,
+            });
+        }
+
+        let attributes = read_attributes(&mut buf_read)?;
+
+        Ok(CodeAttribute {
+            max_stack,
+            max_locals,
+            code,
+            exception_table,
+            attributes,
+        })
+    }
+}
+
+/// Read a count followed by that many constant pool indices, the layout shared by several
+/// attributes that list classes.
+fn read_indices(mut info: &[u8]) -> Result<Vec<u16>, Error> {
+    let count = info.read_u16()?;
+    let mut indices = Vec::with_capacity(capacity_for(info, count, 2)?);
+    for _ in 0..count {
+        indices.push(info.read_u16()?);
+    }
+
+    Ok(indices)
+}
+
+/// Read an `attributes_count` followed by that many `attribute_info` entries.
+pub(crate) fn read_attributes<R>(mut buf_read: R) -> Result<Vec<AttributeInfo>, Error>
+    where R: BufRead,
+{
+    let attributes_count = buf_read.read_u16()?;
+    // Each attribute takes at least its 6-byte name index and length.
+    let mut attributes = Vec::with_capacity(capacity_for(&mut buf_read, attributes_count, 6)?);
+    for _ in 0..attributes_count {
+        let name_index = buf_read.read_u16()?;
+        let length = buf_read.read_u32()?;
+        let info = read_bytes(&mut buf_read, length)?;
+
+        attributes.push(AttributeInfo { name_index, info });
+    }
+
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AttributeInfo, BootstrapMethod, CodeAttribute, ConstantPool, ConstantPoolItem, InnerClassAccessFlags, InnerClassEntry,
+        LineNumber, MethodParameter, ParameterAccessFlags, read_from,
+    };
+
+    #[test]
+    fn test_code_attribute() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("Code".to_string())]);
+        // max_stack = 0, max_locals = 1, code = [return], no exception table or attributes.
+        let attribute = AttributeInfo {
+            name_index: 1,
+            info: vec![0, 0, 0, 1, 0, 0, 0, 1, 0xB1, 0, 0, 0, 0],
+        };
+
+        let code = attribute.as_code(&constant_pool).unwrap().unwrap();
+        assert_eq!(code, CodeAttribute {
+            max_stack: 0,
+            max_locals: 1,
+            code: vec![0xB1],
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        });
+    }
+
+    #[test]
+    fn test_source_file_attribute() {
+        let constant_pool = ConstantPool::from(vec![
+            ConstantPoolItem::Utf8("SourceFile".to_string()),
+            ConstantPoolItem::Utf8("Main.java".to_string()),
+        ]);
+        let attribute = AttributeInfo { name_index: 1, info: vec![0, 2] };
+
+        assert_eq!(attribute.as_source_file(&constant_pool).unwrap().unwrap(), 2);
+        assert!(attribute.as_code(&constant_pool).is_none());
+    }
+
+    #[test]
+    fn test_line_number_table() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Hello.class").as_slice()).unwrap();
+        let main = class_file.methods.iter()
+            .find(|method| class_file.constant_pool.resolve_utf8(method.name_index) == Some("main"))
+            .unwrap();
+
+        let code = main.code(&class_file.constant_pool).unwrap().unwrap();
+        let line_numbers = code.line_numbers(&class_file.constant_pool).unwrap();
+        assert_eq!(line_numbers, vec![
+            LineNumber { start_pc: 0, line_number: 14 },
+            LineNumber { start_pc: 10, line_number: 15 },
+        ]);
+    }
+
+    #[test]
+    fn test_local_variable_table() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Locals.class").as_slice()).unwrap();
+        let pool = &class_file.constant_pool;
+        let main = class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some("main"))
+            .unwrap();
+
+        let code = main.code(pool).unwrap().unwrap();
+        let local_variables = code.local_variables(pool).unwrap();
+        let args = local_variables.iter().find(|variable| variable.index == 0).unwrap();
+        assert_eq!(pool.resolve_utf8(args.name_index), Some("args"));
+        assert_eq!(pool.resolve_utf8(args.descriptor_index), Some("[Ljava/lang/String;"));
+        assert_eq!((args.start_pc, args.length), (0, code.code.len() as u16));
+
+        let names: Vec<_> = local_variables.iter().map(|variable| pool.resolve_utf8(variable.name_index).unwrap()).collect();
+        assert_eq!(names, vec!["arg", "i", "args", "count"]);
+    }
+
+    #[test]
+    fn test_no_local_variable_table() {
+        // Hello was compiled without -g.
+        let class_file = read_from(include_bytes!("../tests/fixtures/Hello.class").as_slice()).unwrap();
+        let code = class_file.methods[0].code(&class_file.constant_pool).unwrap().unwrap();
+
+        assert_eq!(code.local_variables(&class_file.constant_pool).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_bootstrap_methods_attribute() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("BootstrapMethods".to_string())]);
+        let attribute = AttributeInfo {
+            name_index: 1,
+            info: vec![0, 1, 0, 5, 0, 2, 0, 6, 0, 7],
+        };
+
+        assert_eq!(attribute.as_bootstrap_methods(&constant_pool).unwrap().unwrap(), vec![BootstrapMethod {
+            method_ref: 5,
+            arguments: vec![6, 7],
+        }]);
+    }
+
+    #[test]
+    fn test_exceptions_attribute() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("Exceptions".to_string())]);
+        let attribute = AttributeInfo { name_index: 1, info: vec![0, 2, 0, 7, 0, 9] };
+
+        assert_eq!(attribute.as_exceptions(&constant_pool).unwrap().unwrap(), vec![7, 9]);
+    }
+
+    #[test]
+    fn test_inner_classes_attribute() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("InnerClasses".to_string())]);
+        // One private static inner class.
+        let attribute = AttributeInfo { name_index: 1, info: vec![0, 1, 0, 2, 0, 3, 0, 4, 0, 0x0A] };
+
+        let inner_classes = attribute.as_inner_classes(&constant_pool).unwrap().unwrap();
+        assert_eq!(inner_classes, vec![InnerClassEntry {
+            inner_class_info_index: 2,
+            outer_class_info_index: 3,
+            inner_name_index: 4,
+            inner_class_access_flags: InnerClassAccessFlags::PRIVATE | InnerClassAccessFlags::STATIC,
+        }]);
+    }
+
+    #[test]
+    fn test_enclosing_method() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Anonymous$2.class").as_slice()).unwrap();
+        let enclosing_method = class_file.enclosing_method().unwrap().unwrap();
+
+        assert_eq!(class_file.class_name(enclosing_method.class_index), Some("Anonymous"));
+        let method = enclosing_method.method().unwrap();
+        assert_eq!(class_file.name_and_type(method), Some(("make", "()Ljava/lang/Runnable;")));
+    }
+
+    #[test]
+    fn test_enclosing_method_outside_method() {
+        // Anonymous$1 is declared in a static field initializer.
+        let class_file = read_from(include_bytes!("../tests/fixtures/Anonymous$1.class").as_slice()).unwrap();
+        let enclosing_method = class_file.enclosing_method().unwrap().unwrap();
+
+        assert_eq!(class_file.class_name(enclosing_method.class_index), Some("Anonymous"));
+        assert_eq!(enclosing_method.method_index, 0);
+        assert_eq!(enclosing_method.method(), None);
+
+        let outer = read_from(include_bytes!("../tests/fixtures/Anonymous.class").as_slice()).unwrap();
+        assert!(outer.enclosing_method().is_none());
+    }
+
+    #[test]
+    fn test_raw_bytes_after_parsing() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let class_file = read_from(bytes.as_slice()).unwrap();
+        let pool = &class_file.constant_pool;
+        let source_file = class_file.attributes.iter()
+            .find(|attribute| attribute.name(pool) == Some("SourceFile"))
+            .unwrap();
+
+        assert!(source_file.as_source_file(pool).unwrap().is_ok());
+        assert_eq!(source_file.raw(), &[0, 62]);
+        // The SourceFile attribute is 8 bytes: its name, a length of 2, then the raw contents.
+        let header = [source_file.name_index.to_be_bytes().as_slice(), &[0, 0, 0, 2], source_file.raw()].concat();
+        assert!(bytes.windows(header.len()).any(|window| window == header));
+    }
+
+    #[test]
+    fn test_method_parameters() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Params.class").as_slice()).unwrap();
+        let pool = &class_file.constant_pool;
+        let add = class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some("add"))
+            .unwrap();
+
+        let parameters = add.parameters(pool).unwrap();
+        let names: Vec<_> = parameters.iter().map(|parameter| pool.resolve_utf8(parameter.name_index).unwrap()).collect();
+        assert_eq!(names, vec!["first", "second"]);
+        assert_eq!(parameters[0].access_flags, ParameterAccessFlags::default());
+        assert_eq!(parameters[1].access_flags, ParameterAccessFlags::FINAL);
+    }
+
+    #[test]
+    fn test_unnamed_method_parameter() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("MethodParameters".to_string())]);
+        // A single unnamed, mandated parameter.
+        let attribute = AttributeInfo { name_index: 1, info: vec![1, 0, 0, 0x80, 0] };
+
+        assert_eq!(attribute.as_method_parameters(&constant_pool).unwrap().unwrap(), vec![MethodParameter {
+            name_index: 0,
+            access_flags: ParameterAccessFlags::MANDATED,
+        }]);
+    }
+
+    #[test]
+    fn test_as_code_other_attribute() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("SourceFile".to_string())]);
+        let attribute = AttributeInfo { name_index: 1, info: vec![0, 2] };
+
+        assert!(attribute.as_code(&constant_pool).is_none());
+    }
+}
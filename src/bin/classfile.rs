@@ -1,16 +1,40 @@
-use std::fs::File;
+use std::fmt::Write;
+
+use rusty_classfile::descriptor::{parse_field_descriptor, parse_method_descriptor};
+use rusty_classfile::{ClassFile, ConstantPool, ConstantPoolItem};
+
+const USAGE: &str = "usage: classfile [--pretty] [--constant-pool] <path>";
 
 fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // Parse the argument
-    let path = std::env::args().take(2).last().unwrap();
+    // Parse the arguments
+    let mut pretty = false;
+    let mut constant_pool = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--constant-pool" => constant_pool = true,
+            _ => path = Some(arg),
+        }
+    }
+    let path = path.ok_or(USAGE)?;
 
-    println!("opening {path}");
+    if pretty || constant_pool {
+        let class = ClassFile::from_file(path)?;
+        if pretty {
+            print!("{}", render_pretty(&class));
+        }
+        if constant_pool {
+            print!("{}", render_constant_pool(&class));
+        }
+        return Ok(());
+    }
 
-    let raw_file = File::open(path)?;
+    println!("opening {path}");
 
     // Parsing time
     let start = std::time::Instant::now();
-    let class = rusty_classfile::read_from(raw_file)?;
+    let class = ClassFile::from_file(path)?;
     let end = std::time::Instant::now().duration_since(start);
 
     println!("Read class: {class:?}");
@@ -18,3 +42,289 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+/// Render a summary of the class in the style of `javap -p`, with a header like `javap -v`'s.
+fn render_pretty(class: &ClassFile) -> String {
+    let pool = &class.constant_pool;
+    let this_class = class_name(pool, class.this_class);
+    let mut out = String::new();
+
+    if let Some(source_file) = class.source_file() {
+        writeln!(out, "Compiled from \"{source_file}\"").unwrap();
+    }
+
+    // The declaration, e.g. `public final class Foo extends Bar implements Baz`.
+    let mut declaration: Vec<String> = Vec::new();
+    let flags = class.access_flags;
+    if flags.is_public() {
+        declaration.push("public".to_string());
+    }
+    if flags.is_final() {
+        declaration.push("final".to_string());
+    }
+    if flags.is_interface() {
+        declaration.push("interface".to_string());
+    } else {
+        if flags.is_abstract() {
+            declaration.push("abstract".to_string());
+        }
+        declaration.push("class".to_string());
+    }
+    declaration.push(this_class.clone());
+    let super_class = (class.super_class != 0).then(|| class_name(pool, class.super_class));
+    if let Some(super_class) = super_class.as_ref().filter(|name| *name != "java.lang.Object") {
+        declaration.push(format!("extends {super_class}"));
+    }
+    let interfaces: Vec<String> = class.interfaces.iter().map(|&index| class_name(pool, index)).collect();
+    if !interfaces.is_empty() {
+        let keyword = if flags.is_interface() { "extends" } else { "implements" };
+        declaration.push(format!("{keyword} {}", interfaces.join(", ")));
+    }
+    writeln!(out, "{}", declaration.join(" ")).unwrap();
+
+    writeln!(out, "  minor version: {}", class.version.minor()).unwrap();
+    match class.version.java_version() {
+        Some(java_version) => writeln!(out, "  major version: {} ({java_version})", class.version.major()).unwrap(),
+        None => writeln!(out, "  major version: {}", class.version.major()).unwrap(),
+    }
+    let flag_names: Vec<String> = flags.names().map(|name| format!("ACC_{name}")).collect();
+    writeln!(out, "  flags: (0x{:04x}) {}", flags.bits(), flag_names.join(", ")).unwrap();
+    writeln!(out, "  this_class: {this_class}").unwrap();
+    if let Some(super_class) = &super_class {
+        writeln!(out, "  super_class: {super_class}").unwrap();
+    }
+    if !interfaces.is_empty() {
+        writeln!(out, "  interfaces: {}", interfaces.join(", ")).unwrap();
+    }
+
+    writeln!(out, "{{").unwrap();
+    for field in &class.fields {
+        let modifiers = modifiers(field.access_flags.names());
+        let name = utf8(pool, field.name_index);
+        let descriptor = utf8(pool, field.descriptor_index);
+        let field_type = parse_field_descriptor(&descriptor)
+            .map(|field_type| field_type.to_string())
+            .unwrap_or(descriptor);
+
+        writeln!(out, "  {modifiers}{field_type} {name};").unwrap();
+    }
+    for method in &class.methods {
+        let modifiers = modifiers(method.access_flags.names());
+        let name = utf8(pool, method.name_index);
+        let descriptor = utf8(pool, method.descriptor_index);
+
+        if name == "<clinit>" {
+            writeln!(out, "  static {{}};").unwrap();
+            continue;
+        }
+
+        let signature = match parse_method_descriptor(&descriptor) {
+            Ok(parsed) => {
+                let parameters: Vec<String> = parsed.parameters.iter().map(ToString::to_string).collect();
+                let return_type = parsed.return_type.map_or("void".to_string(), |return_type| return_type.to_string());
+                if name == "<init>" {
+                    format!("{this_class}({})", parameters.join(", "))
+                } else {
+                    format!("{return_type} {name}({})", parameters.join(", "))
+                }
+            }
+            Err(_) => format!("{name}{descriptor}"),
+        };
+
+        writeln!(out, "  {modifiers}{signature};").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+/// Render the constant pool in the style of the `Constant pool:` section of `javap -v`, with
+/// each entry's references resolved in a trailing comment.
+fn render_constant_pool(class: &ClassFile) -> String {
+    let pool = &class.constant_pool;
+    let mut out = String::from("Constant pool:\n");
+
+    for (index, item) in class.constant_pool_iter() {
+        let (kind, value, comment) = match item {
+            ConstantPoolItem::Utf8(value) => ("Utf8".to_string(), escape_control(value), None),
+            ConstantPoolItem::Integer(value) => ("Integer".to_string(), value.to_string(), None),
+            ConstantPoolItem::Float(value) => ("Float".to_string(), format!("{value}f"), None),
+            ConstantPoolItem::Long(value) => ("Long".to_string(), format!("{value}l"), None),
+            ConstantPoolItem::Double(value) => ("Double".to_string(), format!("{value}d"), None),
+            ConstantPoolItem::Class { name_index } => {
+                ("Class".to_string(), format!("#{name_index}"), Some(utf8(pool, *name_index)))
+            }
+            ConstantPoolItem::String { string_index } => {
+                ("String".to_string(), format!("#{string_index}"), Some(escape_control(&utf8(pool, *string_index))))
+            }
+            ConstantPoolItem::FieldRef { class_index, name_and_type_index }
+            | ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+            | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+                let kind = match item {
+                    ConstantPoolItem::FieldRef { .. } => "Fieldref",
+                    ConstantPoolItem::MethodRef { .. } => "Methodref",
+                    _ => "InterfaceMethodref",
+                };
+                let comment = member_ref(pool, *class_index, *name_and_type_index);
+                (kind.to_string(), format!("#{class_index}.#{name_and_type_index}"), Some(comment))
+            }
+            ConstantPoolItem::NameAndType { name_index, descriptor_index } => {
+                let comment = name_and_type(pool, index);
+                ("NameAndType".to_string(), format!("#{name_index}:#{descriptor_index}"), Some(comment))
+            }
+            ConstantPoolItem::MethodHandle { reference_kind, reference_index } => {
+                // javap names the kinds after the JVMS constants, e.g. REF_invokeStatic.
+                let kind_name = format!("{reference_kind:?}");
+                let kind_name = kind_name[..1].to_lowercase() + &kind_name[1..];
+                let target = match pool.get(*reference_index) {
+                    Some(ConstantPoolItem::FieldRef { class_index, name_and_type_index }
+                         | ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+                         | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index }) => {
+                        member_ref(pool, *class_index, *name_and_type_index)
+                    }
+                    _ => format!("#{reference_index}"),
+                };
+                let value = format!("{}:#{reference_index}", *reference_kind as u8);
+                ("MethodHandle".to_string(), value, Some(format!("REF_{kind_name} {target}")))
+            }
+            ConstantPoolItem::MethodType { descriptor_index } => {
+                ("MethodType".to_string(), format!("#{descriptor_index}"), Some(utf8(pool, *descriptor_index)))
+            }
+            ConstantPoolItem::Dynamic { bootstrap_method_attr_index, name_and_type_index }
+            | ConstantPoolItem::InvokeDynamic { bootstrap_method_attr_index, name_and_type_index } => {
+                let kind = match item {
+                    ConstantPoolItem::Dynamic { .. } => "Dynamic",
+                    _ => "InvokeDynamic",
+                };
+                let value = format!("#{bootstrap_method_attr_index}:#{name_and_type_index}");
+                let comment = format!("#{bootstrap_method_attr_index}:{}", name_and_type(pool, *name_and_type_index));
+                (kind.to_string(), value, Some(comment))
+            }
+            ConstantPoolItem::Module { name_index } => {
+                ("Module".to_string(), format!("#{name_index}"), Some(utf8(pool, *name_index)))
+            }
+            ConstantPoolItem::Package { name_index } => {
+                ("Package".to_string(), format!("#{name_index}"), Some(utf8(pool, *name_index)))
+            }
+            ConstantPoolItem::Unsupported { tag, raw } => (format!("Unsupported({tag})"), format!("{raw:02x?}"), None),
+        };
+
+        let line = match comment {
+            Some(comment) => format!("{:>5} = {kind:<18} {value:<14} // {comment}", format!("#{index}")),
+            None => format!("{:>5} = {kind:<18} {value}", format!("#{index}")),
+        };
+        writeln!(out, "{}", line.trim_end()).unwrap();
+    }
+
+    out
+}
+
+/// Escape control characters the way javap does, e.g. the `\u0001` placeholders in a
+/// `makeConcatWithConstants` recipe.
+fn escape_control(value: &str) -> String {
+    value.chars()
+        .map(|c| if c.is_control() { format!("\\u{:04x}", c as u32) } else { c.to_string() })
+        .collect()
+}
+
+/// Resolve a FieldRef, MethodRef or InterfaceMethodRef to e.g. `java/lang/Object."<init>":()V`.
+fn member_ref(pool: &ConstantPool, class_index: u16, name_and_type_index: u16) -> String {
+    let class_name = match pool.get(class_index) {
+        Some(ConstantPoolItem::Class { name_index }) => utf8(pool, *name_index),
+        _ => format!("#{class_index}"),
+    };
+
+    format!("{class_name}.{}", name_and_type(pool, name_and_type_index))
+}
+
+/// Resolve a NameAndType entry to e.g. `main:([Ljava/lang/String;)V`, quoting special method
+/// names like `"<init>"`.
+fn name_and_type(pool: &ConstantPool, index: u16) -> String {
+    match pool.get(index) {
+        Some(ConstantPoolItem::NameAndType { name_index, descriptor_index }) => {
+            let name = utf8(pool, *name_index);
+            let name = if name.starts_with('<') { format!("\"{name}\"") } else { name };
+            format!("{name}:{}", utf8(pool, *descriptor_index))
+        }
+        _ => format!("#{index}"),
+    }
+}
+
+/// The Java keywords for a set of member access flags, each followed by a space. Flags without
+/// a keyword, like `SYNTHETIC` or `BRIDGE`, are left out.
+fn modifiers(names: impl Iterator<Item = &'static str>) -> String {
+    names
+        .filter_map(|name| match name {
+            "PUBLIC" | "PRIVATE" | "PROTECTED" | "STATIC" | "FINAL" | "VOLATILE" | "TRANSIENT" | "SYNCHRONIZED"
+            | "NATIVE" | "ABSTRACT" => Some(name.to_lowercase()),
+            "STRICT" => Some("strictfp".to_string()),
+            _ => None,
+        })
+        .map(|keyword| keyword + " ")
+        .collect()
+}
+
+/// Resolve a Utf8 entry, falling back to its index if it doesn't resolve.
+fn utf8(pool: &ConstantPool, index: u16) -> String {
+    pool.resolve_utf8(index).map_or_else(|| format!("#{index}"), ToString::to_string)
+}
+
+/// Resolve a Class entry to its name as written in Java source, e.g. `java.lang.Object`.
+fn class_name(pool: &ConstantPool, index: u16) -> String {
+    match pool.get(index) {
+        Some(ConstantPoolItem::Class { name_index }) => utf8(pool, *name_index).replace('/', "."),
+        _ => format!("#{index}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rusty_classfile::ClassFile;
+
+    use crate::{render_constant_pool, render_pretty};
+
+    #[test]
+    fn test_pretty() {
+        let class = ClassFile::from_bytes(include_bytes!("../../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(render_pretty(&class), "\
+Compiled from \"Hello.java\"
+public class Hello implements java.lang.Runnable
+  minor version: 0
+  major version: 61 (Java 17)
+  flags: (0x0021) ACC_PUBLIC, ACC_SUPER
+  this_class: Hello
+  super_class: java.lang.Object
+  interfaces: java.lang.Runnable
+{
+  static final long ANSWER;
+  private double ratio;
+  public Hello();
+  public void run();
+  public static void main(java.lang.String[]);
+  private static java.lang.String lambda$run$0();
+}
+");
+    }
+
+    #[test]
+    fn test_constant_pool() {
+        let class = ClassFile::from_bytes(include_bytes!("../../tests/fixtures/Hello.class")).unwrap();
+        let dump = render_constant_pool(&class);
+        let lines: Vec<&str> = dump.lines().collect();
+
+        assert_eq!(lines[0], "Constant pool:");
+        for expected in [
+            "   #1 = Methodref          #2.#3          // java/lang/Object.\"<init>\":()V",
+            "   #4 = Utf8               java/lang/Object",
+            "   #7 = Double             1.5d",
+            "  #15 = InvokeDynamic      #0:#16         // #0:get:()Ljava/util/function/Supplier;",
+            "  #53 = Long               42l",
+            "  #83 = Utf8               \\u0001\\u0001",
+        ] {
+            assert!(lines.contains(&expected), "missing {expected:?} in:\n{dump}");
+        }
+        // The slot after a Double is unusable, so there's no #8.
+        assert!(!dump.contains("   #8 ="));
+    }
+}
@@ -0,0 +1,200 @@
+//! A variant of [`ClassFile`] that borrows Utf8 constant pool entries from the input buffer.
+//!
+//! Parsing a class allocates a `String` for every Utf8 entry, which adds up when scanning many
+//! classes. When the whole class is already in memory, [`ClassFileRef::from_bytes`] avoids that
+//! for every entry that is plain ASCII, which is almost all of them in practice. Entries using
+//! any other characters still need decoding from modified UTF-8, so those are allocated.
+
+use std::borrow::Cow;
+use std::io::BufRead;
+
+use crate::{
+    AttributeInfo, capacity_for, ClassAccessFlags, ClassBody, ClassFile, ClassFileVersion, ConstantPool,
+    ConstantPoolEntry, ConstantPoolItem, ConstantPoolItemTag, CountingReader, decode_modified_utf8, EofContext, Error,
+    FieldInfo, MethodInfo, ParseOptions, read_class_body, read_constant_pool_entries_with,
+    read_constant_pool_item_limited, read_version, ReadExt,
+};
+
+/// A constant pool entry that may borrow from the buffer it was parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantPoolItemRef<'a> {
+    /// A Utf8 entry, borrowed if it's ASCII and owned otherwise.
+    Utf8(Cow<'a, str>),
+    /// Any other entry. This is never [`ConstantPoolItem::Utf8`].
+    Other(ConstantPoolItem),
+}
+
+impl ConstantPoolEntry for ConstantPoolItemRef<'_> {
+    fn is_8byte(&self) -> bool {
+        matches!(self, ConstantPoolItemRef::Other(item) if item.is_8byte())
+    }
+}
+
+impl ConstantPoolItemRef<'_> {
+    pub fn into_owned(self) -> ConstantPoolItem {
+        match self {
+            ConstantPoolItemRef::Utf8(value) => ConstantPoolItem::Utf8(value.into_owned()),
+            ConstantPoolItemRef::Other(item) => item,
+        }
+    }
+}
+
+/// A class file parsed by [`ClassFileRef::from_bytes`]. Only the constant pool differs from
+/// [`ClassFile`]: its entries are in file order, without any accounting for wide entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassFileRef<'a> {
+    pub version: ClassFileVersion,
+    pub constant_pool: Vec<ConstantPoolItemRef<'a>>,
+    pub access_flags: ClassAccessFlags,
+    pub this_class: u16,
+    pub super_class: u16,
+    pub interfaces: Vec<u16>,
+    pub fields: Vec<FieldInfo>,
+    pub methods: Vec<MethodInfo>,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+impl<'a> ClassFileRef<'a> {
+    /// Parse a class file held in memory, borrowing ASCII Utf8 entries from `bytes`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<ClassFileRef<'a>, Error> {
+        ClassFileRef::from_bytes_with_options(bytes, ParseOptions::default())
+    }
+
+    /// Like [`from_bytes`](Self::from_bytes), but with the same control over malformed input as
+    /// [`read_from_with_options`](crate::read_from_with_options).
+    pub fn from_bytes_with_options(bytes: &'a [u8], options: ParseOptions) -> Result<ClassFileRef<'a>, Error> {
+        let mut input = CountingReader::new(bytes);
+
+        let version = read_version(&mut input, &options)?;
+
+        let constant_pool_count = input.read_u16().eof_context("constant pool")?;
+        // The smallest entries, such as a Class or an empty Utf8, are 3 bytes.
+        let capacity = capacity_for(&mut input, constant_pool_count.saturating_sub(1), 3).eof_context("constant pool")?;
+        let mut constant_pool = Vec::with_capacity(capacity);
+        read_constant_pool_entries_with(&mut input, constant_pool_count, &options, read_item, |_, item| {
+            constant_pool.push(item)
+        })?;
+
+        let ClassBody { access_flags, this_class, super_class, interfaces, fields, methods, attributes } =
+            read_class_body(&mut input)?;
+
+        Ok(ClassFileRef {
+            version,
+            constant_pool,
+            access_flags,
+            this_class,
+            super_class,
+            interfaces,
+            fields,
+            methods,
+            attributes,
+        })
+    }
+
+    /// Copy any borrowed entries, producing the same [`ClassFile`] that [`ClassFile::from_bytes`]
+    /// would have.
+    pub fn into_owned(self) -> ClassFile {
+        let items: Vec<ConstantPoolItem> = self.constant_pool.into_iter().map(ConstantPoolItemRef::into_owned).collect();
+
+        ClassFile {
+            version: self.version,
+            constant_pool: ConstantPool::from(items),
+            access_flags: self.access_flags,
+            this_class: self.this_class,
+            super_class: self.super_class,
+            interfaces: self.interfaces,
+            fields: self.fields,
+            methods: self.methods,
+            attributes: self.attributes,
+        }
+    }
+}
+
+/// Read a constant pool entry, borrowing it from the input if it's an ASCII Utf8 entry.
+fn read_item<'a>(
+    input: &mut CountingReader<&'a [u8]>,
+    options: &ParseOptions,
+) -> Result<ConstantPoolItemRef<'a>, Error> {
+    if input.fill_buf()?.first() != Some(&(ConstantPoolItemTag::Utf8 as u8)) {
+        return Ok(ConstantPoolItemRef::Other(read_constant_pool_item_limited(input, options.max_utf8_len)?));
+    }
+
+    input.read_u8()?;
+    let strlen = input.read_u16()?;
+    if strlen > options.max_utf8_len {
+        return Err(Error::Utf8TooLong { length: strlen, max: options.max_utf8_len });
+    }
+    let utf8_bytes = input.take_slice(strlen as usize)?;
+
+    // A raw 0x00 byte is ASCII, but never valid modified UTF-8, so let the decoder reject it.
+    if utf8_bytes.iter().all(|&b| b != 0 && b.is_ascii()) {
+        // ASCII is encoded identically in modified UTF-8 and standard UTF-8.
+        Ok(ConstantPoolItemRef::Utf8(Cow::Borrowed(std::str::from_utf8(utf8_bytes).expect("ASCII is valid UTF-8"))))
+    } else {
+        Ok(ConstantPoolItemRef::Utf8(Cow::Owned(decode_modified_utf8(utf8_bytes)?)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use crate::{ClassFile, ClassFileRef, ConstantPoolItemRef, Error, ParseOptions};
+
+    const HELLO: &[u8] = include_bytes!("../tests/fixtures/Hello.class");
+
+    #[test]
+    fn test_borrows_ascii_entries() {
+        let class_file = ClassFileRef::from_bytes(HELLO).unwrap();
+        let source = HELLO.as_ptr_range();
+
+        let mut borrowed = 0;
+        for item in &class_file.constant_pool {
+            match item {
+                ConstantPoolItemRef::Utf8(Cow::Borrowed(value)) => {
+                    assert!(source.contains(&value.as_ptr()), "{value:?} doesn't alias the input");
+                    borrowed += 1;
+                }
+                // The lambda's greeting has non-ASCII characters, so it must be decoded.
+                ConstantPoolItemRef::Utf8(Cow::Owned(value)) => assert!(!value.is_ascii()),
+                ConstantPoolItemRef::Other(_) => {}
+            }
+        }
+        assert!(borrowed > 0);
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let class_file = ClassFileRef::from_bytes(HELLO).unwrap();
+        assert_eq!(class_file.into_owned(), ClassFile::from_bytes(HELLO).unwrap());
+    }
+
+    #[test]
+    fn test_truncated_utf8() {
+        // #4 is the Utf8 "java/lang/Object", whose contents start at byte 26.
+        let err = ClassFileRef::from_bytes(&HELLO[..30]).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { context: "constant pool" }), "{err:?}");
+    }
+
+    #[test]
+    fn test_invalid_tag_offset() {
+        // The header is 10 bytes and the first entry, the MethodRef for Object's constructor, is
+        // another 5, so the tag of the second entry sits at byte 15.
+        let mut bytes = HELLO.to_vec();
+        bytes[15] = 2;
+
+        let err = ClassFileRef::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err, Error::InvalidConstantPoolItemTag { tag: 2, offset: 15 });
+    }
+
+    #[test]
+    fn test_options() {
+        let options = ParseOptions { max_utf8_len: 4, ..ParseOptions::default() };
+        let err = ClassFileRef::from_bytes_with_options(HELLO, options).unwrap_err();
+        assert!(matches!(err, Error::Utf8TooLong { max: 4, .. }), "{err:?}");
+
+        let options = ParseOptions { max_major_version: Some(52), ..ParseOptions::default() };
+        let err = ClassFileRef::from_bytes_with_options(HELLO, options).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedVersion { max: 52, .. }), "{err:?}");
+    }
+}
@@ -0,0 +1,233 @@
+//! A builder for assembling a [`ClassFile`] by hand, keeping track of constant pool indices.
+
+use crate::{
+    AttributeInfo, ClassAccessFlags, ClassFile, ClassFileVersion, ConstantPool, ConstantPoolItem, Error, FieldInfo,
+    MethodInfo,
+};
+
+/// Builds a [`ClassFile`] one piece at a time.
+///
+/// Each `add_*` method for constant pool entries returns the 1-based JVM index the entry was given,
+/// accounting for `Long` and `Double` entries taking up two slots. [`build`](Self::build) then
+/// validates the result, so dangling or mistyped indices are caught before the class is used.
+///
+/// ```
+/// use rusty_classfile::{ClassAccessFlags, ClassFileBuilder, ClassFileVersion};
+///
+/// let mut builder = ClassFileBuilder::new(ClassFileVersion::new(52, 0));
+/// let name = builder.add_utf8("com/example/Empty");
+/// let this_class = builder.add_class(name);
+/// let object = builder.add_utf8("java/lang/Object");
+/// let super_class = builder.add_class(object);
+///
+/// let class_file = builder
+///     .access_flags(ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER)
+///     .this_class(this_class)
+///     .super_class(super_class)
+///     .build()?;
+/// assert_eq!(class_file.this_class_name(), Some("com/example/Empty"));
+/// # Ok::<(), rusty_classfile::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClassFileBuilder {
+    version: ClassFileVersion,
+    constant_pool: Vec<ConstantPoolItem>,
+    /// The number of constant pool slots used so far.
+    slots: u16,
+    access_flags: ClassAccessFlags,
+    this_class: u16,
+    super_class: u16,
+    interfaces: Vec<u16>,
+    fields: Vec<FieldInfo>,
+    methods: Vec<MethodInfo>,
+    attributes: Vec<AttributeInfo>,
+}
+
+impl ClassFileBuilder {
+    pub fn new(version: ClassFileVersion) -> Self {
+        ClassFileBuilder {
+            version,
+            constant_pool: Vec::new(),
+            slots: 0,
+            access_flags: ClassAccessFlags::default(),
+            this_class: 0,
+            super_class: 0,
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Append an entry to the constant pool, returning its index.
+    ///
+    /// # Panics
+    ///
+    /// If the constant pool is already full. It can hold at most 65534 slots.
+    pub fn add_item(&mut self, item: ConstantPoolItem) -> u16 {
+        let width = if item.is_8byte() { 2 } else { 1 };
+        let slots = self.slots.checked_add(width)
+            .filter(|&slots| slots < u16::MAX)
+            .expect("constant pool has more than 65534 slots");
+
+        let index = self.slots + 1;
+        self.constant_pool.push(item);
+        self.slots = slots;
+        index
+    }
+
+    pub fn add_utf8(&mut self, value: &str) -> u16 {
+        self.add_item(ConstantPoolItem::Utf8(value.to_string()))
+    }
+
+    pub fn add_integer(&mut self, value: i32) -> u16 {
+        self.add_item(ConstantPoolItem::Integer(value))
+    }
+
+    pub fn add_long(&mut self, value: i64) -> u16 {
+        self.add_item(ConstantPoolItem::Long(value))
+    }
+
+    /// Add a Class entry, given the index of the Utf8 entry holding its binary name.
+    pub fn add_class(&mut self, name_index: u16) -> u16 {
+        self.add_item(ConstantPoolItem::Class { name_index })
+    }
+
+    /// Add a String entry, given the index of the Utf8 entry holding its value.
+    pub fn add_string(&mut self, string_index: u16) -> u16 {
+        self.add_item(ConstantPoolItem::String { string_index })
+    }
+
+    pub fn add_name_and_type(&mut self, name_index: u16, descriptor_index: u16) -> u16 {
+        self.add_item(ConstantPoolItem::NameAndType { name_index, descriptor_index })
+    }
+
+    pub fn add_field_ref(&mut self, class_index: u16, name_and_type_index: u16) -> u16 {
+        self.add_item(ConstantPoolItem::FieldRef { class_index, name_and_type_index })
+    }
+
+    pub fn add_method_ref(&mut self, class_index: u16, name_and_type_index: u16) -> u16 {
+        self.add_item(ConstantPoolItem::MethodRef { class_index, name_and_type_index })
+    }
+
+    pub fn access_flags(&mut self, access_flags: ClassAccessFlags) -> &mut Self {
+        self.access_flags = access_flags;
+        self
+    }
+
+    /// Set the index of the Class entry for the class being built.
+    pub fn this_class(&mut self, class_index: u16) -> &mut Self {
+        self.this_class = class_index;
+        self
+    }
+
+    /// Set the index of the Class entry for the superclass. Left at 0, the class has none, which
+    /// is only valid for `java/lang/Object`.
+    pub fn super_class(&mut self, class_index: u16) -> &mut Self {
+        self.super_class = class_index;
+        self
+    }
+
+    pub fn add_interface(&mut self, class_index: u16) -> &mut Self {
+        self.interfaces.push(class_index);
+        self
+    }
+
+    pub fn add_field(&mut self, field: FieldInfo) -> &mut Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn add_method(&mut self, method: MethodInfo) -> &mut Self {
+        self.methods.push(method);
+        self
+    }
+
+    pub fn add_attribute(&mut self, attribute: AttributeInfo) -> &mut Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Assemble the class, checking it with [`ClassFile::validate`].
+    pub fn build(&self) -> Result<ClassFile, Error> {
+        let class_file = ClassFile {
+            version: self.version,
+            constant_pool: ConstantPool::from(self.constant_pool.clone()),
+            access_flags: self.access_flags,
+            this_class: self.this_class,
+            super_class: self.super_class,
+            interfaces: self.interfaces.clone(),
+            fields: self.fields.clone(),
+            methods: self.methods.clone(),
+            attributes: self.attributes.clone(),
+        };
+        class_file.validate()?;
+
+        Ok(class_file)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ClassAccessFlags, ClassFileBuilder, ClassFileVersion, ConstantPoolItem, ConstantPoolItemTag, Error, FieldAccessFlags,
+        FieldInfo, read_from, write_to,
+    };
+
+    fn minimal() -> ClassFileBuilder {
+        let mut builder = ClassFileBuilder::new(ClassFileVersion::new(61, 0));
+        let name = builder.add_utf8("Minimal");
+        let this_class = builder.add_class(name);
+        let object = builder.add_utf8("java/lang/Object");
+        let super_class = builder.add_class(object);
+        builder.access_flags(ClassAccessFlags::PUBLIC | ClassAccessFlags::SUPER)
+            .this_class(this_class)
+            .super_class(super_class);
+
+        builder
+    }
+
+    #[test]
+    fn test_build_and_write() {
+        let mut builder = minimal();
+        let name_index = builder.add_utf8("ANSWER");
+        let descriptor_index = builder.add_utf8("J");
+        builder.add_field(FieldInfo {
+            access_flags: FieldAccessFlags::STATIC | FieldAccessFlags::FINAL,
+            name_index,
+            descriptor_index,
+            attributes: Vec::new(),
+        });
+        let class_file = builder.build().unwrap();
+
+        let mut written = Vec::new();
+        write_to(&class_file, &mut written).unwrap();
+
+        let read = read_from(written.as_slice()).unwrap();
+        assert_eq!(read, class_file);
+        assert_eq!(read.this_class_name(), Some("Minimal"));
+        assert_eq!(read.super_class_name(), Some("java/lang/Object"));
+    }
+
+    #[test]
+    fn test_indices_after_wide_entry() {
+        let mut builder = minimal();
+        assert_eq!(builder.add_long(42), 5);
+        assert_eq!(builder.add_integer(7), 7);
+
+        let class_file = builder.build().unwrap();
+        assert_eq!(class_file.constant_pool.get(7), Some(&ConstantPoolItem::Integer(7)));
+    }
+
+    #[test]
+    fn test_build_rejects_bad_index() {
+        let mut builder = minimal();
+        let not_a_class = builder.add_utf8("java/lang/Runnable");
+        builder.add_interface(not_a_class);
+
+        assert!(matches!(builder.build().unwrap_err(), Error::InvalidConstantPoolReference {
+            expected: ConstantPoolItemTag::Class,
+            ..
+        }));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::ConstantPoolItem;
+
+/// The constant pool of a class, addressed the same way the JVM addresses it.
+///
+/// Indices are 1-based, and `Long` and `Double` entries occupy two slots: the index after a wide
+/// entry is unusable, and the entry after it is found at the index after that.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConstantPool {
+    items: Vec<ConstantPoolItem>,
+    /// Maps each JVM index, minus one, to the position of its entry in `items`. The slot after
+    /// each wide entry maps to `None`.
+    slots: Vec<Option<usize>>,
+}
+
+impl ConstantPool {
+    /// Look up the entry at the given JVM index. Returns `None` for index 0, indices past the end
+    /// of the pool, and the unusable slot following a `Long` or `Double`.
+    pub fn get(&self, index: u16) -> Option<&ConstantPoolItem> {
+        let slot = (index as usize).checked_sub(1)?;
+        let position = (*self.slots.get(slot)?)?;
+        Some(&self.items[position])
+    }
+
+    /// Returns true if `index` is the unusable slot following a `Long` or `Double` entry.
+    pub fn is_phantom_slot(&self, index: u16) -> bool {
+        let Some(slot) = (index as usize).checked_sub(1) else {
+            return false;
+        };
+
+        self.slots.get(slot) == Some(&None)
+    }
+
+    /// Look up the Utf8 entry at the given JVM index.
+    pub fn resolve_utf8(&self, index: u16) -> Option<&str> {
+        match self.get(index)? {
+            ConstantPoolItem::Utf8(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Iterate over the entries of the pool along with their JVM index, skipping the unusable
+    /// slot after each wide entry. A pool built from more entries than a class file can hold stops
+    /// at index 65535, the last one a `u16` can address.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &ConstantPoolItem)> {
+        self.slots.iter()
+            .enumerate()
+            .map_while(|(slot, position)| Some((u16::try_from(slot + 1).ok()?, position)))
+            .filter_map(|(index, position)| Some((index, &self.items[(*position)?])))
+    }
+
+    /// The entries of the pool in file order, without any accounting for wide entries.
+    pub fn items(&self) -> &[ConstantPoolItem] {
+        &self.items
+    }
+
+    /// The number of entries in the pool. Wide entries count once.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl From<Vec<ConstantPoolItem>> for ConstantPool {
+    fn from(items: Vec<ConstantPoolItem>) -> Self {
+        let mut slots = Vec::with_capacity(items.len());
+        for (position, item) in items.iter().enumerate() {
+            slots.push(Some(position));
+            if item.is_8byte() {
+                slots.push(None);
+            }
+        }
+
+        ConstantPool { items, slots }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ConstantPool, ConstantPoolItem};
+
+    #[test]
+    fn test_get_after_wide_entry() {
+        let constant_pool = ConstantPool::from(vec![
+            ConstantPoolItem::Long(7),
+            ConstantPoolItem::Utf8("after".to_string()),
+        ]);
+
+        assert_eq!(constant_pool.get(0), None);
+        assert_eq!(constant_pool.get(1), Some(&ConstantPoolItem::Long(7)));
+        assert_eq!(constant_pool.get(2), None);
+        assert_eq!(constant_pool.get(3), Some(&ConstantPoolItem::Utf8("after".to_string())));
+        assert_eq!(constant_pool.get(4), None);
+    }
+
+    #[test]
+    fn test_is_phantom_slot() {
+        let constant_pool = ConstantPool::from(vec![
+            ConstantPoolItem::Double(1.0),
+            ConstantPoolItem::Integer(2),
+        ]);
+
+        assert!(!constant_pool.is_phantom_slot(0));
+        assert!(!constant_pool.is_phantom_slot(1));
+        assert!(constant_pool.is_phantom_slot(2));
+        assert!(!constant_pool.is_phantom_slot(3));
+        assert!(!constant_pool.is_phantom_slot(4));
+    }
+
+    #[test]
+    fn test_resolve_utf8() {
+        let constant_pool = ConstantPool::from(vec![
+            ConstantPoolItem::Utf8("java/lang/Object".to_string()),
+            ConstantPoolItem::Class { name_index: 1 },
+        ]);
+
+        assert_eq!(constant_pool.resolve_utf8(1), Some("java/lang/Object"));
+        assert_eq!(constant_pool.resolve_utf8(2), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let constant_pool = ConstantPool::from(vec![
+            ConstantPoolItem::Integer(1),
+            ConstantPoolItem::Double(2.0),
+            ConstantPoolItem::Integer(3),
+        ]);
+
+        let indices: Vec<u16> = constant_pool.iter().map(|(index, _)| index).collect();
+        assert_eq!(indices, vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_iter_stops_at_last_addressable_index() {
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Integer(0); u16::MAX as usize + 2]);
+
+        assert_eq!(constant_pool.iter().count(), u16::MAX as usize);
+        assert_eq!(constant_pool.iter().last().map(|(index, _)| index), Some(u16::MAX));
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{BufRead, Read};
+
+/// A reader that keeps track of how many bytes have been consumed from the reader it wraps.
+///
+/// When wrapping a `BufRead`, only bytes handed to the caller are counted, not whatever the inner
+/// reader has buffered ahead.
+pub(crate) struct CountingReader<R> {
+    inner: R,
+    count: usize,
+}
+
+impl<R> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        CountingReader { inner, count: 0 }
+    }
+
+    /// The number of bytes consumed so far.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    pub(crate) fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<'a> CountingReader<&'a [u8]> {
+    /// Take the next `len` bytes straight out of the slice being read, borrowing rather than
+    /// copying them.
+    pub(crate) fn take_slice(&mut self, len: usize) -> Result<&'a [u8], std::io::Error> {
+        if self.inner.len() < len {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+        }
+        let (taken, rest) = self.inner.split_at(len);
+        self.inner = rest;
+        self.count += len;
+
+        Ok(taken)
+    }
+}
+
+impl<R> Read for CountingReader<R> where R: Read {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count += read;
+        Ok(read)
+    }
+}
+
+impl<R> BufRead for CountingReader<R> where R: BufRead {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.count += amt;
+        self.inner.consume(amt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Read};
+
+    use crate::counting::CountingReader;
+
+    #[test]
+    fn test_counts_consumed_bytes_only() {
+        let mut reader = CountingReader::new(BufReader::new([1u8, 2, 3, 4, 5].as_slice()));
+
+        let mut buf = [0u8; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(reader.count(), 2);
+
+        // Peeking at the buffer doesn't count, consuming does.
+        assert_eq!(reader.fill_buf().unwrap(), &[3, 4, 5]);
+        assert_eq!(reader.count(), 2);
+        reader.consume(1);
+        assert_eq!(reader.count(), 3);
+    }
+}
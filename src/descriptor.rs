@@ -0,0 +1,182 @@
+//! Parsing of field and method descriptors, such as `[[I` or `(Ljava/lang/String;I)Z`.
+
+use std::fmt;
+
+use crate::Error;
+
+/// The JVM spec caps arrays at 255 dimensions.
+const MAX_ARRAY_DIMENSIONS: usize = 255;
+
+/// The type of a field, parameter or return value, as described by a field descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    Byte,
+    Char,
+    Double,
+    Float,
+    Int,
+    Long,
+    Short,
+    Boolean,
+    /// A class or interface type, holding its binary name, e.g. `java/lang/String`.
+    Object(String),
+    /// An array of the given component type.
+    Array(Box<FieldType>),
+}
+
+/// Formats the type as it would be written in Java source, e.g. `int[][]` or `java.lang.String`.
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldType::Byte => f.write_str("byte"),
+            FieldType::Char => f.write_str("char"),
+            FieldType::Double => f.write_str("double"),
+            FieldType::Float => f.write_str("float"),
+            FieldType::Int => f.write_str("int"),
+            FieldType::Long => f.write_str("long"),
+            FieldType::Short => f.write_str("short"),
+            FieldType::Boolean => f.write_str("boolean"),
+            // Binary names use '/' between package components where source uses '.'.
+            FieldType::Object(name) => f.write_str(&name.replace('/', ".")),
+            FieldType::Array(component) => write!(f, "{component}[]"),
+        }
+    }
+}
+
+/// The parameter and return types of a method, as described by a method descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodDescriptor {
+    pub parameters: Vec<FieldType>,
+    /// The return type, or `None` for `void`.
+    pub return_type: Option<FieldType>,
+}
+
+/// Parse a field descriptor such as `I`, `Ljava/lang/Object;` or `[[D`.
+pub fn parse_field_descriptor(descriptor: &str) -> Result<FieldType, Error> {
+    let (field_type, rest) = read_field_type(descriptor)
+        .ok_or_else(|| Error::InvalidDescriptor(descriptor.to_string()))?;
+    if !rest.is_empty() {
+        return Err(Error::InvalidDescriptor(descriptor.to_string()));
+    }
+
+    Ok(field_type)
+}
+
+/// Parse a method descriptor such as `()V` or `(Ljava/lang/String;I)Z`.
+pub fn parse_method_descriptor(descriptor: &str) -> Result<MethodDescriptor, Error> {
+    read_method_descriptor(descriptor).ok_or_else(|| Error::InvalidDescriptor(descriptor.to_string()))
+}
+
+fn read_method_descriptor(descriptor: &str) -> Option<MethodDescriptor> {
+    let mut rest = descriptor.strip_prefix('(')?;
+
+    let mut parameters = Vec::new();
+    while !rest.starts_with(')') {
+        let (parameter, next) = read_field_type(rest)?;
+        parameters.push(parameter);
+        rest = next;
+    }
+    rest = &rest[1..];
+
+    let return_type = if rest == "V" {
+        None
+    } else {
+        let (return_type, rest) = read_field_type(rest)?;
+        if !rest.is_empty() {
+            return None;
+        }
+        Some(return_type)
+    };
+
+    Some(MethodDescriptor { parameters, return_type })
+}
+
+/// Read a single field type from the start of `descriptor`, returning it and the remaining input.
+fn read_field_type(descriptor: &str) -> Option<(FieldType, &str)> {
+    let dimensions = descriptor.bytes().take_while(|&b| b == b'[').count();
+    if dimensions > MAX_ARRAY_DIMENSIONS {
+        return None;
+    }
+
+    let rest = &descriptor[dimensions..];
+    let (mut field_type, rest) = match rest.as_bytes().first()? {
+        b'B' => (FieldType::Byte, &rest[1..]),
+        b'C' => (FieldType::Char, &rest[1..]),
+        b'D' => (FieldType::Double, &rest[1..]),
+        b'F' => (FieldType::Float, &rest[1..]),
+        b'I' => (FieldType::Int, &rest[1..]),
+        b'J' => (FieldType::Long, &rest[1..]),
+        b'S' => (FieldType::Short, &rest[1..]),
+        b'Z' => (FieldType::Boolean, &rest[1..]),
+        b'L' => {
+            let end = rest.find(';')?;
+            let name = &rest[1..end];
+            if name.is_empty() {
+                return None;
+            }
+            (FieldType::Object(name.to_string()), &rest[end + 1..])
+        }
+        _ => return None,
+    };
+
+    for _ in 0..dimensions {
+        field_type = FieldType::Array(Box::new(field_type));
+    }
+
+    Some((field_type, rest))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::{FieldType, MethodDescriptor, parse_field_descriptor, parse_method_descriptor};
+    use crate::Error;
+
+    #[test]
+    fn test_nested_array() {
+        assert_eq!(
+            parse_field_descriptor("[[I").unwrap(),
+            FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Int)))),
+        );
+    }
+
+    #[test]
+    fn test_object() {
+        assert_eq!(parse_field_descriptor("Ljava/lang/String;").unwrap(), FieldType::Object("java/lang/String".to_string()));
+    }
+
+    #[test]
+    fn test_method_descriptor() {
+        assert_eq!(parse_method_descriptor("(Ljava/lang/String;I)Z").unwrap(), MethodDescriptor {
+            parameters: vec![FieldType::Object("java/lang/String".to_string()), FieldType::Int],
+            return_type: Some(FieldType::Boolean),
+        });
+    }
+
+    #[test]
+    fn test_void_method_descriptor() {
+        assert_eq!(parse_method_descriptor("()V").unwrap(), MethodDescriptor {
+            parameters: Vec::new(),
+            return_type: None,
+        });
+    }
+
+    #[test]
+    fn test_display() {
+        let display = |descriptor| parse_field_descriptor(descriptor).unwrap().to_string();
+
+        assert_eq!(display("Z"), "boolean");
+        assert_eq!(display("[[I"), "int[][]");
+        assert_eq!(display("Ljava/lang/String;"), "java.lang.String");
+        assert_eq!(display("[Ljava/lang/Object;"), "java.lang.Object[]");
+        assert_eq!(display("[[[Ljava/util/Map$Entry;"), "java.util.Map$Entry[][][]");
+    }
+
+    #[test]
+    fn test_invalid_descriptors() {
+        for descriptor in ["", "V", "Q", "II", "Ljava/lang/String", "L;", "[", "(I", "()", "(V)V", "()II"] {
+            let invalid = parse_field_descriptor(descriptor).is_err() && parse_method_descriptor(descriptor).is_err();
+            assert!(invalid, "{descriptor:?} should be rejected");
+        }
+        assert!(matches!(parse_field_descriptor("X").unwrap_err(), Error::InvalidDescriptor(d) if d == "X"));
+    }
+}
@@ -0,0 +1,207 @@
+//! Parsing of a class's structure without reading attribute bodies, for classes held in a
+//! seekable source.
+//!
+//! Attributes like `Code`, `LineNumberTable` or `LocalVariableTable` usually make up most of a
+//! class file. [`read_lazy`] records where each one is in the input instead of reading it, and
+//! [`LazyAttribute::read`] seeks back to fetch the body when it's actually needed.
+
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{
+    AttributeInfo, capacity_for, ClassAccessFlags, ClassFileVersion, ConstantPool, CountingReader, EofContext, Error,
+    FieldAccessFlags, MethodAccessFlags, ParseOptions, read_bytes, read_constant_pool_entries, read_interfaces,
+    read_version, ReadExt,
+};
+
+/// Where an attribute's body is in the input that [`read_lazy`] read it from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LazyAttribute {
+    pub name_index: u16,
+    /// The position of the body in the reader, just past the attribute's length.
+    pub offset: u64,
+    pub length: u32,
+}
+
+impl LazyAttribute {
+    /// Resolve the attribute's name in `constant_pool`, like [`AttributeInfo::name`].
+    pub fn name<'a>(&self, constant_pool: &'a ConstantPool) -> Option<&'a str> {
+        constant_pool.resolve_utf8(self.name_index)
+    }
+
+    /// Seek to the attribute's body in `reader` and read it. `reader` must be positioned the same
+    /// way as the one that was passed to [`read_lazy`].
+    pub fn read<R>(&self, mut reader: R) -> Result<AttributeInfo, Error>
+        where R: Read + Seek {
+        reader.seek(SeekFrom::Start(self.offset))?;
+        let info = read_bytes(&mut reader, self.length).eof_context("attributes")?;
+
+        Ok(AttributeInfo { name_index: self.name_index, info })
+    }
+}
+
+/// A field or method read by [`read_lazy`], with its attributes left unread.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LazyMember<F> {
+    pub access_flags: F,
+    pub name_index: u16,
+    pub descriptor_index: u16,
+    pub attributes: Vec<LazyAttribute>,
+}
+
+/// The structure of a class file, as read by [`read_lazy`]. Everything but attribute bodies is
+/// parsed as it would be by [`read_from`](crate::read_from).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LazyClassFile {
+    pub version: ClassFileVersion,
+    pub constant_pool: ConstantPool,
+    pub access_flags: ClassAccessFlags,
+    pub this_class: u16,
+    pub super_class: u16,
+    pub interfaces: Vec<u16>,
+    pub fields: Vec<LazyMember<FieldAccessFlags>>,
+    pub methods: Vec<LazyMember<MethodAccessFlags>>,
+    pub attributes: Vec<LazyAttribute>,
+}
+
+/// Parse a class file from `reader`, skipping over attribute bodies rather than reading them.
+///
+/// Pass `&mut reader` to keep using the reader afterwards with [`LazyAttribute::read`]. Since the
+/// bodies are skipped, an input that's cut off partway through its attributes is only detected
+/// when the missing ones are read.
+pub fn read_lazy<R>(reader: R) -> Result<LazyClassFile, Error>
+    where R: Read + Seek {
+    read_lazy_with_options(reader, ParseOptions::default())
+}
+
+/// Like [`read_lazy`], but with the same control over malformed input as
+/// [`read_from_with_options`](crate::read_from_with_options).
+pub fn read_lazy_with_options<R>(reader: R, options: ParseOptions) -> Result<LazyClassFile, Error>
+    where R: Read + Seek {
+    let mut counting = CountingReader::new(BufReader::new(reader));
+
+    let version = read_version(&mut counting, &options)?;
+
+    let constant_pool_count = counting.read_u16().eof_context("constant pool")?;
+    // The smallest entries, such as a Class or an empty Utf8, are 3 bytes.
+    let capacity = capacity_for(&mut counting, constant_pool_count.saturating_sub(1), 3).eof_context("constant pool")?;
+    let mut constant_pool_items = Vec::with_capacity(capacity);
+    read_constant_pool_entries(&mut counting, constant_pool_count, &options, |_, item| constant_pool_items.push(item))?;
+
+    // Nothing after the constant pool needs the count, and attributes are skipped by seeking the
+    // `BufReader` itself.
+    let mut buf_read = counting.into_inner();
+
+    let access_flags = ClassAccessFlags::from_bits_retain(buf_read.read_u16().eof_context("access flags")?);
+    let this_class = buf_read.read_u16().eof_context("this_class")?;
+    let super_class = buf_read.read_u16().eof_context("super_class")?;
+    let interfaces = read_interfaces(&mut buf_read)?;
+
+    let fields_count = buf_read.read_u16().eof_context("fields")?;
+    // The flags, name, descriptor and attributes count of a member make up 8 bytes.
+    let mut fields = Vec::with_capacity(capacity_for(&mut buf_read, fields_count, 8).eof_context("fields")?);
+    for _ in 0..fields_count {
+        let access_flags = FieldAccessFlags::from_bits_retain(buf_read.read_u16().eof_context("fields")?);
+        fields.push(read_lazy_member(&mut buf_read, access_flags).eof_context("fields")?);
+    }
+
+    let methods_count = buf_read.read_u16().eof_context("methods")?;
+    let mut methods = Vec::with_capacity(capacity_for(&mut buf_read, methods_count, 8).eof_context("methods")?);
+    for _ in 0..methods_count {
+        let access_flags = MethodAccessFlags::from_bits_retain(buf_read.read_u16().eof_context("methods")?);
+        methods.push(read_lazy_member(&mut buf_read, access_flags).eof_context("methods")?);
+    }
+
+    let attributes = read_lazy_attributes(&mut buf_read).eof_context("attributes")?;
+
+    Ok(LazyClassFile {
+        version,
+        constant_pool: ConstantPool::from(constant_pool_items),
+        access_flags,
+        this_class,
+        super_class,
+        interfaces,
+        fields,
+        methods,
+        attributes,
+    })
+}
+
+fn read_lazy_member<R, F>(buf_read: &mut BufReader<R>, access_flags: F) -> Result<LazyMember<F>, Error>
+    where R: Read + Seek {
+    let name_index = buf_read.read_u16()?;
+    let descriptor_index = buf_read.read_u16()?;
+    let attributes = read_lazy_attributes(buf_read)?;
+
+    Ok(LazyMember { access_flags, name_index, descriptor_index, attributes })
+}
+
+fn read_lazy_attributes<R>(buf_read: &mut BufReader<R>) -> Result<Vec<LazyAttribute>, Error>
+    where R: Read + Seek {
+    let attributes_count = buf_read.read_u16()?;
+    // Each attribute takes at least its 6-byte name index and length, even when its body is
+    // skipped.
+    let mut attributes = Vec::with_capacity(capacity_for(&mut *buf_read, attributes_count, 6)?);
+    for _ in 0..attributes_count {
+        let name_index = buf_read.read_u16()?;
+        let length = buf_read.read_u32()?;
+        let offset = buf_read.stream_position()?;
+        buf_read.seek_relative(length as i64)?;
+
+        attributes.push(LazyAttribute { name_index, offset, length });
+    }
+
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{ClassFile, Error, ParseOptions, read_lazy, read_lazy_with_options};
+
+    const HELLO: &[u8] = include_bytes!("../tests/fixtures/Hello.class");
+
+    #[test]
+    fn test_read_attributes_after_skeleton() {
+        let mut cursor = Cursor::new(HELLO);
+        let lazy = read_lazy(&mut cursor).unwrap();
+        let class_file = ClassFile::from_bytes(HELLO).unwrap();
+
+        assert_eq!(lazy.constant_pool, class_file.constant_pool);
+        assert_eq!(lazy.methods.len(), class_file.methods.len());
+
+        // Read the attributes back in reverse, so every one needs a seek backwards.
+        for (lazy, method) in lazy.methods.iter().zip(&class_file.methods).rev() {
+            assert_eq!(lazy.name_index, method.name_index);
+            for (lazy, attribute) in lazy.attributes.iter().zip(&method.attributes).rev() {
+                assert_eq!(&lazy.read(&mut cursor).unwrap(), attribute);
+            }
+        }
+
+        let source_file = lazy.attributes.iter()
+            .find(|attribute| attribute.name(&lazy.constant_pool) == Some("SourceFile"))
+            .unwrap();
+        let source_file = source_file.read(&mut cursor).unwrap();
+        let index = source_file.as_source_file(&lazy.constant_pool).unwrap().unwrap();
+        assert_eq!(lazy.constant_pool.resolve_utf8(index), Some("Hello.java"));
+    }
+
+    #[test]
+    fn test_invalid_tag_offset() {
+        // The header is 10 bytes and the first entry, the MethodRef for Object's constructor, is
+        // another 5, so the tag of the second entry sits at byte 15.
+        let mut bytes = HELLO.to_vec();
+        bytes[15] = 2;
+
+        let err = read_lazy(Cursor::new(bytes)).unwrap_err();
+        assert_eq!(err, Error::InvalidConstantPoolItemTag { tag: 2, offset: 15 });
+    }
+
+    #[test]
+    fn test_max_utf8_len() {
+        let options = ParseOptions { max_utf8_len: 4, ..ParseOptions::default() };
+
+        let err = read_lazy_with_options(Cursor::new(HELLO), options).unwrap_err();
+        assert!(matches!(err, Error::Utf8TooLong { max: 4, .. }), "{err:?}");
+    }
+}
@@ -1,22 +1,125 @@
-use std::io::{BufRead, BufReader, Read};
+//! `classfile` is a library providing read-only access to a JVM ClassFile structure.
+
+use std::fmt;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
 use std::string::FromUtf8Error;
 
 #[macro_use]
 pub(crate) mod macros;
+mod access_flags;
+mod annotations;
+mod attributes;
+mod borrowed;
+mod builder;
+mod constant_pool;
+mod counting;
+pub mod descriptor;
+mod lazy;
+mod mutf8;
+mod semantic;
+mod stack_map;
+mod visitor;
 
-///! `classfile` is a library providing read-only access to a JVM ClassFile structure.
-///
+pub use access_flags::{ClassAccessFlags, FieldAccessFlags, InnerClassAccessFlags, MethodAccessFlags, ParameterAccessFlags};
+pub use annotations::{Annotation, ElementValue};
+pub use attributes::{
+    AttributeInfo, BootstrapMethod, CodeAttribute, EnclosingMethod, ExceptionTableEntry, InnerClassEntry, LineNumber,
+    LocalVariable, MethodParameter,
+};
+use attributes::read_attributes;
+pub use borrowed::{ClassFileRef, ConstantPoolItemRef};
+pub use builder::ClassFileBuilder;
+pub use constant_pool::ConstantPool;
+use counting::CountingReader;
+pub use lazy::{LazyAttribute, LazyClassFile, LazyMember, read_lazy, read_lazy_with_options};
+pub use mutf8::{decode_modified_utf8, encode_modified_utf8};
+pub use stack_map::{StackMapFrame, VerificationTypeInfo};
+pub use visitor::{ClassVisitor, read_with_visitor, read_with_visitor_with_options};
 
 pub static MAGIC: [u8; 4] = [0xCA, 0xFE, 0xBA, 0xBE];
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+// NOTE: The derived ordering compares fields in declaration order, so the major version must stay
+// first for it to dominate the minor version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ClassFileVersion(
     /* Major */ u16,
     /* Minor */ u16);
 
+impl ClassFileVersion {
+    pub fn new(major: u16, minor: u16) -> Self {
+        ClassFileVersion(major, minor)
+    }
+
+    /// The major version of the class file, e.g. 52 for Java 8.
+    ///
+    /// ```
+    /// // An empty Java 8 class.
+    /// let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 1];
+    /// bytes.extend_from_slice(&[0; 14]);
+    ///
+    /// let class_file = rusty_classfile::read_from(bytes.as_slice())?;
+    /// assert_eq!(class_file.version.major(), 52);
+    /// assert_eq!(class_file.version.minor(), 0);
+    /// # Ok::<(), rusty_classfile::Error>(())
+    /// ```
+    pub fn major(&self) -> u16 {
+        self.0
+    }
+
+    /// The minor version of the class file. This is 0 for almost all modern class files.
+    pub fn minor(&self) -> u16 {
+        self.1
+    }
+
+    /// The name of the Java release that introduced this major version, e.g. `"Java 8"` for 52.
+    /// Returns `None` for major versions this crate doesn't know about.
+    pub fn java_version(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            45 => "Java 1.1",
+            46 => "Java 1.2",
+            47 => "Java 1.3",
+            48 => "Java 1.4",
+            49 => "Java 5",
+            50 => "Java 6",
+            51 => "Java 7",
+            52 => "Java 8",
+            53 => "Java 9",
+            54 => "Java 10",
+            55 => "Java 11",
+            56 => "Java 12",
+            57 => "Java 13",
+            58 => "Java 14",
+            59 => "Java 15",
+            60 => "Java 16",
+            61 => "Java 17",
+            62 => "Java 18",
+            63 => "Java 19",
+            64 => "Java 20",
+            65 => "Java 21",
+            66 => "Java 22",
+            67 => "Java 23",
+            68 => "Java 24",
+            69 => "Java 25",
+            70 => "Java 26",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+
+    /// Whether the class depends on preview features of its Java release, which javac marks by
+    /// setting the minor version to 0xFFFF. Preview features were introduced in Java 12.
+    pub fn is_preview(&self) -> bool {
+        self.1 == 0xFFFF && self.0 >= 56
+    }
+}
 
 reversible_enum! {
-    ConstantPoolItemTag as u8,
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    ConstantPoolItemTag as u8 => invalid_constant_pool_item_tag,
     {
         Utf8 = 1,
         Integer = 3,
@@ -31,33 +134,691 @@ reversible_enum! {
         NameAndType = 12,
         MethodHandle = 15,
         MethodType = 16,
+        Dynamic = 17,
         InvokeDynamic = 18,
+        Module = 19,
+        Package = 20,
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+reversible_enum! {
+    /// The kind of a `CONSTANT_MethodHandle_info`, describing the bytecode behavior of the handle.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    ReferenceKind as u8 => Error::InvalidReferenceKind,
+    {
+        GetField = 1,
+        GetStatic = 2,
+        PutField = 3,
+        PutStatic = 4,
+        InvokeVirtual = 5,
+        InvokeStatic = 6,
+        InvokeSpecial = 7,
+        NewInvokeSpecial = 8,
+        InvokeInterface = 9,
+    }
+}
+
+impl ReferenceKind {
+    /// The type of constant pool entry a handle of this kind must point at: a FieldRef for the
+    /// field accessors, an InterfaceMethodRef for `InvokeInterface`, and a MethodRef otherwise.
+    ///
+    /// From class file version 52, `InvokeStatic` and `InvokeSpecial` handles may also point at
+    /// an InterfaceMethodRef, for static and private interface methods.
+    pub fn expected_ref_tag(&self) -> ConstantPoolItemTag {
+        match self {
+            ReferenceKind::GetField
+            | ReferenceKind::GetStatic
+            | ReferenceKind::PutField
+            | ReferenceKind::PutStatic => ConstantPoolItemTag::FieldRef,
+            ReferenceKind::InvokeVirtual
+            | ReferenceKind::InvokeStatic
+            | ReferenceKind::InvokeSpecial
+            | ReferenceKind::NewInvokeSpecial => ConstantPoolItemTag::MethodRef,
+            ReferenceKind::InvokeInterface => ConstantPoolItemTag::InterfaceMethodRef,
+        }
+    }
+}
+
+/// An entry in the constant pool.
+///
+/// `Float` and `Double` entries are compared and hashed by their bit patterns, the way they're
+/// stored in the class file. That makes equality an equivalence relation, so NaN equals itself,
+/// but `0.0` and `-0.0` are different entries.
+#[derive(Debug, Clone)]
 pub enum ConstantPoolItem {
     Utf8(String),
     Integer(i32),
     Float(f32),
     Long(i64),
     Double(f64),
-    Unsupported,
+    /// `CONSTANT_Class_info`, holding the index of the Utf8 entry with the class's binary name.
+    Class { name_index: u16 },
+    /// `CONSTANT_String_info`, holding the index of the Utf8 entry with the string's contents.
+    ///
+    /// The index is stored exactly as it appears in the file, so it is 1-based like every other
+    /// constant pool index: `string_index == 1` refers to the first entry in the pool.
+    String { string_index: u16 },
+    /// `CONSTANT_Fieldref_info`, pointing at the owning Class and the field's NameAndType.
+    FieldRef { class_index: u16, name_and_type_index: u16 },
+    /// `CONSTANT_Methodref_info`, pointing at the owning Class and the method's NameAndType.
+    MethodRef { class_index: u16, name_and_type_index: u16 },
+    /// `CONSTANT_InterfaceMethodref_info`, pointing at the owning interface and the method's NameAndType.
+    InterfaceMethodRef { class_index: u16, name_and_type_index: u16 },
+    /// `CONSTANT_NameAndType_info`, pointing at the Utf8 entries for a member's name and descriptor.
+    NameAndType { name_index: u16, descriptor_index: u16 },
+    /// `CONSTANT_MethodHandle_info`, pointing at the field or method entry the handle refers to.
+    MethodHandle { reference_kind: ReferenceKind, reference_index: u16 },
+    /// `CONSTANT_MethodType_info`, pointing at the Utf8 entry holding a method descriptor.
+    MethodType { descriptor_index: u16 },
+    /// `CONSTANT_Dynamic_info`, a dynamically-computed constant produced by a bootstrap method.
+    Dynamic {
+        /// Index into the `bootstrap_methods` table of the class's `BootstrapMethods` attribute.
+        bootstrap_method_attr_index: u16,
+        name_and_type_index: u16,
+    },
+    /// `CONSTANT_InvokeDynamic_info`, describing the call site of an `invokedynamic` instruction.
+    InvokeDynamic {
+        /// Index into the `bootstrap_methods` table of the class's `BootstrapMethods` attribute.
+        /// Unlike every other index here, this does *not* point into the constant pool.
+        bootstrap_method_attr_index: u16,
+        name_and_type_index: u16,
+    },
+    /// `CONSTANT_Module_info`, found in `module-info.class`, pointing at the Utf8 module name.
+    Module { name_index: u16 },
+    /// `CONSTANT_Package_info`, found in `module-info.class`, pointing at the Utf8 package name.
+    Package { name_index: u16 },
+    /// An entry this crate can't interpret, kept as its tag and the raw bytes that followed it so
+    /// that it can be written back out unchanged. Parsing never produces one: an unknown tag gives
+    /// no way to tell where its entry ends, so it fails with [`Error::InvalidConstantPoolItemTag`].
+    Unsupported { tag: u8, raw: Vec<u8> },
 }
 
 impl ConstantPoolItem {
     pub fn is_8byte(&self) -> bool {
-        match &self {
-            ConstantPoolItem::Long(_) | ConstantPoolItem::Double(_) => true,
-            _ => false
+        matches!(self, ConstantPoolItem::Long(_) | ConstantPoolItem::Double(_))
+    }
+
+    /// Returns `(class_index, name_and_type_index)` for any of the FieldRef, MethodRef or
+    /// InterfaceMethodRef entries, and `None` for everything else.
+    pub fn ref_indices(&self) -> Option<(u16, u16)> {
+        match *self {
+            ConstantPoolItem::FieldRef { class_index, name_and_type_index }
+            | ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+            | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+                Some((class_index, name_and_type_index))
+            }
+            _ => None,
+        }
+    }
+
+    /// The tag this entry is stored under in a class file, or `None` for an unsupported entry.
+    pub fn tag(&self) -> Option<ConstantPoolItemTag> {
+        let tag = match self {
+            ConstantPoolItem::Utf8(_) => ConstantPoolItemTag::Utf8,
+            ConstantPoolItem::Integer(_) => ConstantPoolItemTag::Integer,
+            ConstantPoolItem::Float(_) => ConstantPoolItemTag::Float,
+            ConstantPoolItem::Long(_) => ConstantPoolItemTag::Long,
+            ConstantPoolItem::Double(_) => ConstantPoolItemTag::Double,
+            ConstantPoolItem::Class { .. } => ConstantPoolItemTag::Class,
+            ConstantPoolItem::String { .. } => ConstantPoolItemTag::String,
+            ConstantPoolItem::FieldRef { .. } => ConstantPoolItemTag::FieldRef,
+            ConstantPoolItem::MethodRef { .. } => ConstantPoolItemTag::MethodRef,
+            ConstantPoolItem::InterfaceMethodRef { .. } => ConstantPoolItemTag::InterfaceMethodRef,
+            ConstantPoolItem::NameAndType { .. } => ConstantPoolItemTag::NameAndType,
+            ConstantPoolItem::MethodHandle { .. } => ConstantPoolItemTag::MethodHandle,
+            ConstantPoolItem::MethodType { .. } => ConstantPoolItemTag::MethodType,
+            ConstantPoolItem::Dynamic { .. } => ConstantPoolItemTag::Dynamic,
+            ConstantPoolItem::InvokeDynamic { .. } => ConstantPoolItemTag::InvokeDynamic,
+            ConstantPoolItem::Module { .. } => ConstantPoolItemTag::Module,
+            ConstantPoolItem::Package { .. } => ConstantPoolItemTag::Package,
+            ConstantPoolItem::Unsupported { .. } => return None,
+        };
+
+        Some(tag)
+    }
+}
+
+/// Floats and doubles compare by their bits, so that a `NaN` entry equals itself and `0.0` and
+/// `-0.0` are different entries, as they are in the constant pool.
+impl PartialEq for ConstantPoolItem {
+    fn eq(&self, other: &Self) -> bool {
+        use ConstantPoolItem::*;
+
+        match (self, other) {
+            (Utf8(l), Utf8(r)) => l == r,
+            (Integer(l), Integer(r)) => l == r,
+            (Float(l), Float(r)) => l.to_bits() == r.to_bits(),
+            (Long(l), Long(r)) => l == r,
+            (Double(l), Double(r)) => l.to_bits() == r.to_bits(),
+            (Class { name_index: l }, Class { name_index: r })
+            | (String { string_index: l }, String { string_index: r })
+            | (MethodType { descriptor_index: l }, MethodType { descriptor_index: r })
+            | (Module { name_index: l }, Module { name_index: r })
+            | (Package { name_index: l }, Package { name_index: r }) => l == r,
+            (FieldRef { class_index: lc, name_and_type_index: lnt }, FieldRef { class_index: rc, name_and_type_index: rnt })
+            | (MethodRef { class_index: lc, name_and_type_index: lnt }, MethodRef { class_index: rc, name_and_type_index: rnt })
+            | (
+                InterfaceMethodRef { class_index: lc, name_and_type_index: lnt },
+                InterfaceMethodRef { class_index: rc, name_and_type_index: rnt },
+            ) => lc == rc && lnt == rnt,
+            (
+                NameAndType { name_index: ln, descriptor_index: ld },
+                NameAndType { name_index: rn, descriptor_index: rd },
+            ) => ln == rn && ld == rd,
+            (
+                MethodHandle { reference_kind: lk, reference_index: li },
+                MethodHandle { reference_kind: rk, reference_index: ri },
+            ) => lk == rk && li == ri,
+            (
+                Dynamic { bootstrap_method_attr_index: lb, name_and_type_index: lnt },
+                Dynamic { bootstrap_method_attr_index: rb, name_and_type_index: rnt },
+            )
+            | (
+                InvokeDynamic { bootstrap_method_attr_index: lb, name_and_type_index: lnt },
+                InvokeDynamic { bootstrap_method_attr_index: rb, name_and_type_index: rnt },
+            ) => lb == rb && lnt == rnt,
+            (Unsupported { tag: lt, raw: lr }, Unsupported { tag: rt, raw: rr }) => lt == rt && lr == rr,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ConstantPoolItem {}
+
+impl Hash for ConstantPoolItem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        use ConstantPoolItem::*;
+
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Utf8(value) => value.hash(state),
+            Integer(value) => value.hash(state),
+            Float(value) => value.to_bits().hash(state),
+            Long(value) => value.hash(state),
+            Double(value) => value.to_bits().hash(state),
+            Class { name_index: index }
+            | String { string_index: index }
+            | MethodType { descriptor_index: index }
+            | Module { name_index: index }
+            | Package { name_index: index } => index.hash(state),
+            FieldRef { class_index: first, name_and_type_index: second }
+            | MethodRef { class_index: first, name_and_type_index: second }
+            | InterfaceMethodRef { class_index: first, name_and_type_index: second }
+            | NameAndType { name_index: first, descriptor_index: second }
+            | Dynamic { bootstrap_method_attr_index: first, name_and_type_index: second }
+            | InvokeDynamic { bootstrap_method_attr_index: first, name_and_type_index: second } => {
+                first.hash(state);
+                second.hash(state);
+            }
+            MethodHandle { reference_kind, reference_index } => {
+                reference_kind.hash(state);
+                reference_index.hash(state);
+            }
+            Unsupported { tag, raw } => {
+                tag.hash(state);
+                raw.hash(state);
+            }
+        }
+    }
+}
+
+/// A concise, single-line form of the entry, e.g. `Class(#7)` or `MethodRef(#3.#12)`. Indices
+/// are left unresolved.
+impl fmt::Display for ConstantPoolItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstantPoolItem::Utf8(value) => write!(f, "Utf8({value:?})"),
+            ConstantPoolItem::Integer(value) => write!(f, "Integer({value})"),
+            ConstantPoolItem::Float(value) => write!(f, "Float({value:?})"),
+            ConstantPoolItem::Long(value) => write!(f, "Long({value})"),
+            ConstantPoolItem::Double(value) => write!(f, "Double({value:?})"),
+            ConstantPoolItem::Class { name_index } => write!(f, "Class(#{name_index})"),
+            ConstantPoolItem::String { string_index } => write!(f, "String(#{string_index})"),
+            ConstantPoolItem::FieldRef { class_index, name_and_type_index } => {
+                write!(f, "FieldRef(#{class_index}.#{name_and_type_index})")
+            }
+            ConstantPoolItem::MethodRef { class_index, name_and_type_index } => {
+                write!(f, "MethodRef(#{class_index}.#{name_and_type_index})")
+            }
+            ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+                write!(f, "InterfaceMethodRef(#{class_index}.#{name_and_type_index})")
+            }
+            ConstantPoolItem::NameAndType { name_index, descriptor_index } => {
+                write!(f, "NameAndType(#{name_index}:#{descriptor_index})")
+            }
+            ConstantPoolItem::MethodHandle { reference_kind, reference_index } => {
+                write!(f, "MethodHandle({reference_kind:?} #{reference_index})")
+            }
+            ConstantPoolItem::MethodType { descriptor_index } => write!(f, "MethodType(#{descriptor_index})"),
+            // The bootstrap method index isn't a constant pool index, so it goes without a '#'.
+            ConstantPoolItem::Dynamic { bootstrap_method_attr_index, name_and_type_index } => {
+                write!(f, "Dynamic({bootstrap_method_attr_index}:#{name_and_type_index})")
+            }
+            ConstantPoolItem::InvokeDynamic { bootstrap_method_attr_index, name_and_type_index } => {
+                write!(f, "InvokeDynamic({bootstrap_method_attr_index}:#{name_and_type_index})")
+            }
+            ConstantPoolItem::Module { name_index } => write!(f, "Module(#{name_index})"),
+            ConstantPoolItem::Package { name_index } => write!(f, "Package(#{name_index})"),
+            ConstantPoolItem::Unsupported { tag, raw } => write!(f, "Unsupported(tag {tag}, {} bytes)", raw.len()),
         }
     }
 }
 
+/// A `field_info` entry from the class's fields table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInfo {
+    pub access_flags: FieldAccessFlags,
+    pub name_index: u16,
+    pub descriptor_index: u16,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+impl FieldInfo {
+    /// The compile-time constant value of a `static final` field, resolved through its
+    /// `ConstantValue` attribute.
+    pub fn constant_value<'a>(&self, constant_pool: &'a ConstantPool) -> Option<&'a ConstantPoolItem> {
+        let index = self.attributes.iter()
+            .find_map(|attribute| attribute.as_constant_value(constant_pool)?.ok())?;
+        constant_pool.get(index)
+    }
+
+    /// Whether the field carries a `Deprecated` attribute, as javac adds for `@Deprecated` fields.
+    pub fn is_deprecated(&self, constant_pool: &ConstantPool) -> bool {
+        has_attribute(&self.attributes, constant_pool, "Deprecated")
+    }
+
+    /// Whether the field was generated by the compiler, marked either by `ACC_SYNTHETIC` or, in
+    /// classes older than Java 5, by a `Synthetic` attribute.
+    pub fn is_synthetic(&self, constant_pool: &ConstantPool) -> bool {
+        self.access_flags.is_synthetic() || has_attribute(&self.attributes, constant_pool, "Synthetic")
+    }
+}
+
+/// A `method_info` entry from the class's methods table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodInfo {
+    pub access_flags: MethodAccessFlags,
+    pub name_index: u16,
+    pub descriptor_index: u16,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+impl MethodInfo {
+    /// Parse the method's `Code` attribute. Returns `None` for abstract and native methods.
+    pub fn code(&self, constant_pool: &ConstantPool) -> Option<Result<CodeAttribute, Error>> {
+        self.attributes.iter().find_map(|attribute| attribute.as_code(constant_pool))
+    }
+
+    /// The indices of the Class entries for the checked exceptions this method declares in its
+    /// `throws` clause, or an empty list if it declares none.
+    pub fn exceptions(&self, constant_pool: &ConstantPool) -> Result<Vec<u16>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_exceptions(constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// The entries of the method's `MethodParameters` attribute, or an empty list if it has none.
+    /// javac only emits the attribute when compiling with `-parameters`.
+    pub fn parameters(&self, constant_pool: &ConstantPool) -> Result<Vec<MethodParameter>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_method_parameters(constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// Whether the method carries a `Deprecated` attribute, as javac adds for `@Deprecated` methods.
+    pub fn is_deprecated(&self, constant_pool: &ConstantPool) -> bool {
+        has_attribute(&self.attributes, constant_pool, "Deprecated")
+    }
+
+    /// Whether the method was generated by the compiler, marked either by `ACC_SYNTHETIC` or, in
+    /// classes older than Java 5, by a `Synthetic` attribute.
+    pub fn is_synthetic(&self, constant_pool: &ConstantPool) -> bool {
+        self.access_flags.is_synthetic() || has_attribute(&self.attributes, constant_pool, "Synthetic")
+    }
+}
+
+/// Whether any of `attributes` is called `name`. Marker attributes like `Deprecated` are meant to
+/// be empty, but their presence is what counts, so the contents aren't checked.
+fn has_attribute(attributes: &[AttributeInfo], constant_pool: &ConstantPool, name: &str) -> bool {
+    attributes.iter().any(|attribute| attribute.name(constant_pool) == Some(name))
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ClassFile {
     pub version: ClassFileVersion,
-    pub constant_pool: Vec<ConstantPoolItem>,
+    pub constant_pool: ConstantPool,
+    pub access_flags: ClassAccessFlags,
+    /// Constant pool index of the Class entry for this class.
+    pub this_class: u16,
+    /// Constant pool index of the Class entry for the direct superclass, or 0 for `java.lang.Object`.
+    pub super_class: u16,
+    /// Constant pool indices of the Class entries for each directly implemented interface.
+    pub interfaces: Vec<u16>,
+    pub fields: Vec<FieldInfo>,
+    pub methods: Vec<MethodInfo>,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+/// A method reference with every index resolved, as returned by [`ClassFile::resolve_method_ref`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MethodRefInfo<'a> {
+    /// The binary name of the class declaring the method, e.g. `java/io/PrintStream`.
+    pub class_name: &'a str,
+    pub method_name: &'a str,
+    pub descriptor: &'a str,
+}
+
+/// Formats the reference the way `javap` does, e.g. `java/io/PrintStream.println:(I)V`.
+impl fmt::Display for MethodRefInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}:{}", self.class_name, self.method_name, self.descriptor)
+    }
+}
+
+impl ClassFile {
+    /// Parse a class file held in memory.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ClassFile, Error> {
+        read_from_buffered(bytes)
+    }
+
+    /// A 64-bit FNV-1a hash of a class file's raw bytes, for caching and change detection. It's
+    /// stable across platforms and releases, but it isn't cryptographic, so it's no defense
+    /// against deliberately crafted collisions.
+    pub fn content_hash(bytes: &[u8]) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        bytes.iter().fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+    }
+
+    /// Open and parse the class file at `path`.
+    pub fn from_file<P>(path: P) -> Result<ClassFile, Error>
+        where P: AsRef<Path> {
+        read_from(File::open(path)?)
+    }
+
+    /// The constant pool entries, in file order. Positions in this slice aren't JVM indices, since
+    /// those skip a slot after each `Long` and `Double`; use [`ConstantPool::get`] to look entries up
+    /// by index.
+    ///
+    /// Like every resolution helper here, this only borrows the class, so there's never any need
+    /// to clone it to get at names:
+    ///
+    /// ```
+    /// use rusty_classfile::ClassFile;
+    ///
+    /// fn method_names(class_file: &ClassFile) -> Vec<&str> {
+    ///     class_file.methods.iter()
+    ///         .filter_map(|method| class_file.constant_pool.resolve_utf8(method.name_index))
+    ///         .collect()
+    /// }
+    ///
+    /// let class_file = ClassFile::from_file("tests/fixtures/Hello.class")?;
+    /// assert_eq!(method_names(&class_file), vec!["<init>", "run", "main", "lambda$run$0"]);
+    /// assert!(class_file.constant_pool().len() < class_file.constant_pool_slot_count() as usize);
+    /// # Ok::<(), rusty_classfile::Error>(())
+    /// ```
+    pub fn constant_pool(&self) -> &[ConstantPoolItem] {
+        self.constant_pool.items()
+    }
+
+    /// Iterate over the constant pool, yielding each entry with its 1-based JVM index.
+    pub fn constant_pool_iter(&self) -> impl Iterator<Item = (u16, &ConstantPoolItem)> {
+        self.constant_pool.iter()
+    }
+
+    /// The number of slots the constant pool takes up, counting two for each `Long` and `Double`.
+    /// This is one less than the `constant_pool_count` stored in the file. A pool too big to be
+    /// written out reports `u16::MAX`.
+    pub fn constant_pool_slot_count(&self) -> u16 {
+        let slots = self.constant_pool.items().iter()
+            .map(|item| if item.is_8byte() { 2 } else { 1 })
+            .sum::<usize>();
+        u16::try_from(slots).unwrap_or(u16::MAX)
+    }
+
+    /// Resolve the Class entry at `class_index` to its binary name, e.g. `java/util/List`.
+    /// Returns `None` if the index is out of range or doesn't point at a Class entry whose name
+    /// resolves.
+    pub fn class_name(&self, class_index: u16) -> Option<&str> {
+        match self.constant_pool.get(class_index)? {
+            ConstantPoolItem::Class { name_index } => self.constant_pool.resolve_utf8(*name_index),
+            _ => None,
+        }
+    }
+
+    /// Resolve the NameAndType entry at `index` to its member name and descriptor, e.g.
+    /// `("<init>", "()V")`. Returns `None` if the index doesn't point at a NameAndType entry or
+    /// either of its strings doesn't resolve.
+    pub fn name_and_type(&self, index: u16) -> Option<(&str, &str)> {
+        match self.constant_pool.get(index)? {
+            ConstantPoolItem::NameAndType { name_index, descriptor_index } => Some((
+                self.constant_pool.resolve_utf8(*name_index)?,
+                self.constant_pool.resolve_utf8(*descriptor_index)?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Resolve the MethodRef or InterfaceMethodRef entry at `index` to the names it refers to.
+    /// Returns `None` if the index points at anything else, or any part of it doesn't resolve.
+    pub fn resolve_method_ref(&self, index: u16) -> Option<MethodRefInfo<'_>> {
+        let (class_index, name_and_type_index) = match self.constant_pool.get(index)? {
+            ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+            | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+                (*class_index, *name_and_type_index)
+            }
+            _ => return None,
+        };
+        let (method_name, descriptor) = self.name_and_type(name_and_type_index)?;
+
+        Some(MethodRefInfo { class_name: self.class_name(class_index)?, method_name, descriptor })
+    }
+
+    /// The binary name of this class, e.g. `com/example/Main`.
+    pub fn this_class_name(&self) -> Option<&str> {
+        self.class_name(self.this_class)
+    }
+
+    /// The binary name of this class's direct superclass. This is `None` for `java/lang/Object`,
+    /// which has no superclass.
+    pub fn super_class_name(&self) -> Option<&str> {
+        self.class_name(self.super_class)
+    }
+
+    /// The binary names of every other class this class refers to through its constant pool,
+    /// sorted and without duplicates. Field, method and interface method references all point at
+    /// a Class entry, so this covers the owners of every member used.
+    ///
+    /// Array classes such as `[[Ljava/lang/String;` count as a reference to their element class,
+    /// and arrays of primitives, like `[I`, are left out.
+    pub fn referenced_class_names(&self) -> Vec<&str> {
+        let this_class_name = self.this_class_name();
+        let names: std::collections::BTreeSet<&str> = self.constant_pool.items().iter()
+            .filter_map(|item| match item {
+                ConstantPoolItem::Class { name_index } => self.constant_pool.resolve_utf8(*name_index),
+                _ => None,
+            })
+            .filter_map(|name| {
+                let element = name.trim_start_matches('[');
+                if element.len() == name.len() {
+                    Some(name)
+                } else {
+                    element.strip_prefix('L')?.strip_suffix(';')
+                }
+            })
+            .filter(|&name| Some(name) != this_class_name)
+            .collect();
+
+        names.into_iter().collect()
+    }
+
+    /// Whether `invokespecial` instructions in this class use the modern semantics for calling
+    /// superclass methods, which `ACC_SUPER` opts into. From Java 8 on, the JVM applies them to
+    /// every class regardless of the flag, so this is always true for version 52 and later.
+    pub fn treats_invokespecial_specially(&self) -> bool {
+        self.access_flags.is_super() || self.version.major() >= 52
+    }
+
+    /// The name of the source file this class was compiled from, from its `SourceFile` attribute.
+    pub fn source_file(&self) -> Option<&str> {
+        let index = self.attributes.iter()
+            .find_map(|attribute| attribute.as_source_file(&self.constant_pool)?.ok())?;
+        self.constant_pool.resolve_utf8(index)
+    }
+
+    /// The entries of the class's `BootstrapMethods` attribute, or an empty list if it has none.
+    pub fn bootstrap_methods(&self) -> Result<Vec<BootstrapMethod>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_bootstrap_methods(&self.constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// The entries of the class's `InnerClasses` attribute, or an empty list if it has none.
+    pub fn inner_classes(&self) -> Result<Vec<InnerClassEntry>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_inner_classes(&self.constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// The fields and methods that are part of the class's API, i.e. those that are `public` or
+    /// `protected`, in the order they're declared.
+    pub fn public_members(&self) -> (Vec<&FieldInfo>, Vec<&MethodInfo>) {
+        let fields = self.fields.iter()
+            .filter(|field| field.access_flags.is_public() || field.access_flags.is_protected())
+            .collect();
+        let methods = self.methods.iter()
+            .filter(|method| method.access_flags.is_public() || method.access_flags.is_protected())
+            .collect();
+
+        (fields, methods)
+    }
+
+    /// The index of the Class entry for this class's nest host, from its `NestHost` attribute.
+    /// Returns `None` if the class has none, in which case it hosts its own nest.
+    pub fn nest_host(&self) -> Option<Result<u16, Error>> {
+        self.attributes.iter().find_map(|attribute| attribute.as_nest_host(&self.constant_pool))
+    }
+
+    /// The indices of the Class entries for the members of the nest this class hosts, from its
+    /// `NestMembers` attribute, or an empty list if it has none. Nestmates may access each other's
+    /// private members.
+    pub fn nest_members(&self) -> Result<Vec<u16>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_nest_members(&self.constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// The indices of the Class entries for the permitted subclasses of a sealed class, from its
+    /// `PermittedSubclasses` attribute, or an empty list if it isn't sealed.
+    pub fn permitted_subclasses(&self) -> Result<Vec<u16>, Error> {
+        self.attributes.iter()
+            .find_map(|attribute| attribute.as_permitted_subclasses(&self.constant_pool))
+            .unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    /// The binary names of the permitted subclasses of a sealed class, e.g. `com/example/Circle`.
+    /// Fails if any of the entries doesn't resolve to a Class.
+    pub fn permitted_subclass_names(&self) -> Result<Vec<&str>, Error> {
+        self.permitted_subclasses()?.into_iter()
+            .map(|index| {
+                self.class_name(index)
+                    .ok_or(Error::InvalidConstantPoolReference { index, expected: ConstantPoolItemTag::Class })
+            })
+            .collect()
+    }
+
+    /// Parse the class's `EnclosingMethod` attribute. Returns `None` unless this is a local or
+    /// anonymous class.
+    pub fn enclosing_method(&self) -> Option<Result<EnclosingMethod, Error>> {
+        self.attributes.iter().find_map(|attribute| attribute.as_enclosing_method(&self.constant_pool))
+    }
+
+    /// Check that every constant pool index in the class points at an entry of the expected type.
+    ///
+    /// Parsing doesn't do this on its own, so a corrupt file can produce a `ClassFile` with
+    /// dangling references.
+    pub fn validate(&self) -> Result<(), Error> {
+        use ConstantPoolItemTag as Tag;
+
+        for item in self.constant_pool.items() {
+            match *item {
+                ConstantPoolItem::Class { name_index } => self.check_reference(name_index, Tag::Utf8)?,
+                ConstantPoolItem::String { string_index } => self.check_reference(string_index, Tag::Utf8)?,
+                ConstantPoolItem::FieldRef { class_index, name_and_type_index }
+                | ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+                | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+                    self.check_reference(class_index, Tag::Class)?;
+                    self.check_reference(name_and_type_index, Tag::NameAndType)?;
+                }
+                ConstantPoolItem::NameAndType { name_index, descriptor_index } => {
+                    self.check_reference(name_index, Tag::Utf8)?;
+                    self.check_reference(descriptor_index, Tag::Utf8)?;
+                }
+                ConstantPoolItem::MethodHandle { reference_kind, reference_index } => {
+                    let interface_allowed = self.version.major() >= 52
+                        && matches!(reference_kind, ReferenceKind::InvokeStatic | ReferenceKind::InvokeSpecial);
+                    let is_interface = self.constant_pool.get(reference_index).and_then(ConstantPoolItem::tag)
+                        == Some(Tag::InterfaceMethodRef);
+                    if !(interface_allowed && is_interface) {
+                        self.check_reference(reference_index, reference_kind.expected_ref_tag())?;
+                    }
+                }
+                ConstantPoolItem::MethodType { descriptor_index } => self.check_reference(descriptor_index, Tag::Utf8)?,
+                ConstantPoolItem::Dynamic { name_and_type_index, .. }
+                | ConstantPoolItem::InvokeDynamic { name_and_type_index, .. } => {
+                    self.check_reference(name_and_type_index, Tag::NameAndType)?;
+                }
+                ConstantPoolItem::Module { name_index }
+                | ConstantPoolItem::Package { name_index } => self.check_reference(name_index, Tag::Utf8)?,
+                _ => {}
+            }
+        }
+
+        self.check_reference(self.this_class, Tag::Class)?;
+        if self.super_class != 0 {
+            self.check_reference(self.super_class, Tag::Class)?;
+        }
+        for &interface in &self.interfaces {
+            self.check_reference(interface, Tag::Class)?;
+        }
+
+        let members = self.fields.iter()
+            .map(|field| (field.name_index, field.descriptor_index, &field.attributes))
+            .chain(self.methods.iter().map(|method| (method.name_index, method.descriptor_index, &method.attributes)));
+        for (name_index, descriptor_index, attributes) in members {
+            self.check_reference(name_index, Tag::Utf8)?;
+            self.check_reference(descriptor_index, Tag::Utf8)?;
+            for attribute in attributes {
+                self.check_reference(attribute.name_index, Tag::Utf8)?;
+            }
+        }
+        for attribute in &self.attributes {
+            self.check_reference(attribute.name_index, Tag::Utf8)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_reference(&self, index: u16, expected: ConstantPoolItemTag) -> Result<(), Error> {
+        if self.constant_pool.is_phantom_slot(index) {
+            return Err(Error::ReferenceToWidePhantomSlot { index });
+        }
+
+        match self.constant_pool.get(index).and_then(ConstantPoolItem::tag) {
+            Some(tag) if tag == expected => Ok(()),
+            _ => Err(Error::InvalidConstantPoolReference { index, expected }),
+        }
+    }
+}
+
+/// Parses the bytes with [`ClassFile::from_bytes`].
+impl TryFrom<&[u8]> for ClassFile {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        ClassFile::from_bytes(bytes)
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -68,16 +829,126 @@ pub enum Error {
     #[error("utf8 decode error: {0}")]
     Utf8DecodeError(#[from] FromUtf8Error),
 
+    #[error("modified utf8 decode error at byte {offset}: {reason}")]
+    ModifiedUtf8Error { offset: usize, reason: &'static str },
+
     #[error("Invalid magic in file header: {0:?}")]
     InvalidMagic([u8; 4]),
 
-    #[error("Invalid constant_pool_item tag: {0}")]
-    InvalidConstantPoolItemTag(u8),
+    /// `offset` is the position of the tag byte from the start of the input. When a single entry
+    /// is read with [`read_constant_pool_item`], that is always 0.
+    #[error("Invalid constant_pool_item tag {tag} at byte {offset}")]
+    InvalidConstantPoolItemTag { tag: u8, offset: u64 },
+
+    #[error("Invalid method handle reference_kind: {0}")]
+    InvalidReferenceKind(u8),
+
+    #[error("Invalid constant pool reference to #{index}, expected a {expected:?} entry")]
+    InvalidConstantPoolReference { index: u16, expected: ConstantPoolItemTag },
+
+    /// The reference points at the unusable slot following a `Long` or `Double` entry.
+    #[error("Invalid constant pool reference to #{index}, which is the second slot of a Long or Double")]
+    ReferenceToWidePhantomSlot { index: u16 },
+
+    #[error("Invalid descriptor: {0:?}")]
+    InvalidDescriptor(String),
+
+    #[error("Invalid annotation element_value tag: {:?}", *.0 as char)]
+    InvalidElementValueTag(u8),
+
+    #[error("Invalid stack map frame_type: {0}")]
+    InvalidStackMapFrameType(u8),
+
+    #[error("Invalid verification_type_info tag: {0}")]
+    InvalidVerificationTypeTag(u8),
+
+    /// The input ended partway through the class file. `context` names the part being read.
+    #[error("Unexpected end of file while reading {context}")]
+    UnexpectedEof { context: &'static str },
+
+    #[error("Unsupported class file version {major}.{minor}, the newest supported major version is {max}")]
+    UnsupportedVersion { major: u16, minor: u16, max: u16 },
+
+    #[error("Utf8 constant of {length} bytes is longer than the limit of {max}")]
+    Utf8TooLong { length: u16, max: u16 },
+
+    #[error("Attribute values are nested more than {max_depth} levels deep")]
+    AttributeNestingTooDeep { max_depth: usize },
+}
+
+/// I/O errors can't be compared directly, so two `IoError`s are equal if they have the same
+/// [`ErrorKind`](std::io::ErrorKind). Every other variant compares its contents.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        use Error::*;
+
+        match (self, other) {
+            (IoError(l), IoError(r)) => l.kind() == r.kind(),
+            (Utf8DecodeError(l), Utf8DecodeError(r)) => l == r,
+            (ModifiedUtf8Error { offset: lo, reason: lr }, ModifiedUtf8Error { offset: ro, reason: rr }) => {
+                lo == ro && lr == rr
+            }
+            (InvalidMagic(l), InvalidMagic(r)) => l == r,
+            (InvalidConstantPoolItemTag { tag: lt, offset: lo }, InvalidConstantPoolItemTag { tag: rt, offset: ro }) => {
+                lt == rt && lo == ro
+            }
+            (InvalidReferenceKind(l), InvalidReferenceKind(r))
+            | (InvalidElementValueTag(l), InvalidElementValueTag(r))
+            | (InvalidStackMapFrameType(l), InvalidStackMapFrameType(r))
+            | (InvalidVerificationTypeTag(l), InvalidVerificationTypeTag(r)) => l == r,
+            (
+                InvalidConstantPoolReference { index: li, expected: le },
+                InvalidConstantPoolReference { index: ri, expected: re },
+            ) => li == ri && le == re,
+            (ReferenceToWidePhantomSlot { index: l }, ReferenceToWidePhantomSlot { index: r }) => l == r,
+            (InvalidDescriptor(l), InvalidDescriptor(r)) => l == r,
+            (UnexpectedEof { context: l }, UnexpectedEof { context: r }) => l == r,
+            (
+                UnsupportedVersion { major: lmaj, minor: lmin, max: lmax },
+                UnsupportedVersion { major: rmaj, minor: rmin, max: rmax },
+            ) => lmaj == rmaj && lmin == rmin && lmax == rmax,
+            (Utf8TooLong { length: ll, max: lm }, Utf8TooLong { length: rl, max: rm }) => ll == rl && lm == rm,
+            (AttributeNestingTooDeep { max_depth: l }, AttributeNestingTooDeep { max_depth: r }) => l == r,
+            _ => false,
+        }
+    }
+}
+
+/// Turns an end-of-file I/O error into an [`Error::UnexpectedEof`] naming what was being read.
+trait EofContext<T> {
+    fn eof_context(self, context: &'static str) -> Result<T, Error>;
+}
+
+impl<T, E> EofContext<T> for Result<T, E> where E: Into<Error> {
+    fn eof_context(self, context: &'static str) -> Result<T, Error> {
+        self.map_err(|err| match err.into() {
+            Error::IoError(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Error::UnexpectedEof { context },
+            err => err,
+        })
+    }
+}
+
+fn invalid_constant_pool_item_tag(tag: u8) -> Error {
+    Error::InvalidConstantPoolItemTag { tag, offset: 0 }
 }
 
-trait ReadExt: Read {
+/// Big-endian reads of the fixed-size values that make up a class file, implemented for every
+/// [`Read`].
+///
+/// This is handy for parsing the payload of attributes this crate doesn't know about:
+///
+/// ```
+/// use rusty_classfile::ReadExt;
+///
+/// let mut info: &[u8] = &[0x00, 0x2A, 0xCA, 0xFE, 0xBA, 0xBE];
+/// assert_eq!(info.read_u16()?, 42);
+/// assert_eq!(info.read_u32()?, 0xCAFEBABE);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait ReadExt: Read {
     fn read_u8(&mut self) -> Result<u8, std::io::Error>;
     fn read_u16(&mut self) -> Result<u16, std::io::Error>;
+    fn read_u32(&mut self) -> Result<u32, std::io::Error>;
 
     fn read_i32(&mut self) -> Result<i32, std::io::Error>;
     fn read_i64(&mut self) -> Result<i64, std::io::Error>;
@@ -94,6 +965,10 @@ impl<R> ReadExt for R where R: Read {
         read_bytes!(self, u16, 2)
     }
 
+    fn read_u32(&mut self) -> Result<u32, std::io::Error> {
+        read_bytes!(self, u32, 4)
+    }
+
     fn read_i32(&mut self) -> Result<i32, std::io::Error> {
         read_bytes!(self, i32, 4)
     }
@@ -111,164 +986,1874 @@ impl<R> ReadExt for R where R: Read {
     }
 }
 
+trait WriteExt: Write {
+    fn write_u8(&mut self, value: u8) -> Result<(), std::io::Error>;
+    fn write_u16(&mut self, value: u16) -> Result<(), std::io::Error>;
+    fn write_u32(&mut self, value: u32) -> Result<(), std::io::Error>;
 
-pub fn read_from<R>(reader: R) -> Result<ClassFile, Error>
-    where R: Read {
-    let mut buf_read = BufReader::new(reader);
+    fn write_i32(&mut self, value: i32) -> Result<(), std::io::Error>;
+    fn write_i64(&mut self, value: i64) -> Result<(), std::io::Error>;
+    fn write_f32(&mut self, value: f32) -> Result<(), std::io::Error>;
+    fn write_f64(&mut self, value: f64) -> Result<(), std::io::Error>;
 
-    // Try and read until we're able to retrieve a single read var here.
-    let mut buf: [u8; 4] = [0u8; 4];
+    /// Write a table length as a u16, failing if it doesn't fit.
+    fn write_count(&mut self, count: usize) -> Result<(), std::io::Error> {
+        let count = u16::try_from(count)
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "table has more than 65535 entries"))?;
+        self.write_u16(count)
+    }
+}
 
-    buf_read.read_exact(&mut buf)?;
+impl<W> WriteExt for W where W: Write {
+    fn write_u8(&mut self, value: u8) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
+    }
 
-    if MAGIC != buf {
-        return Err(Error::InvalidMagic(buf));
+    fn write_u16(&mut self, value: u16) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
     }
 
-    // Read major and minor versions
-    let minor = buf_read.read_u16()?;
-    let major = buf_read.read_u16()?;
+    fn write_u32(&mut self, value: u32) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
+    }
 
-    // NOTE: For some reason the JVM stores this as N+1, and uses 1-based indexing for items.
-    let constant_pool_count = buf_read.read_u16()? - 1;
-    let mut constant_pool_items = Vec::new();
-    println!("count = {constant_pool_count}");
+    fn write_i32(&mut self, value: i32) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
+    }
 
-    {
-        let mut constant_pool_index = 0;
-        loop {
-            if constant_pool_index >= constant_pool_count {
-                break;
-            }
+    fn write_i64(&mut self, value: i64) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
+    }
 
-            let item = read_constant_pool_item(&mut buf_read)?;
-            // JVM oddity: 64-bit types occupy 2 slots in the constant pool.
-            if item.is_8byte() {
-                constant_pool_index += 2
-            } else {
-                constant_pool_index += 1
-            }
+    fn write_f32(&mut self, value: f32) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
+    }
 
-            constant_pool_items.push(item);
-        }
+    fn write_f64(&mut self, value: f64) -> Result<(), std::io::Error> {
+        self.write_all(&value.to_be_bytes())
     }
+}
 
-    let access_flags = buf_read.read_u16()?;
-    let this_class = buf_read.read_u16()?;
-    let super_class = buf_read.read_u16()?;
-    let interfaces_count = buf_read.read_u16()?;
-    // Read a bunch of interfaces.
+/// Options controlling how forgiving [`read_from_with_options`] is of malformed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The newest major version to accept. Classes with a greater major version fail with
+    /// [`Error::UnsupportedVersion`] before anything past the version is read.
+    pub max_major_version: Option<u16>,
+    /// The longest Utf8 entry to accept, in encoded bytes. Longer entries fail with
+    /// [`Error::Utf8TooLong`]. The default is `u16::MAX`, the limit the format itself imposes.
+    pub max_utf8_len: u16,
+    /// How many levels deep nested values in an attribute may go, such as annotations within
+    /// annotations or arrays within arrays, before parsing it fails with
+    /// [`Error::AttributeNestingTooDeep`]. This keeps a corrupt attribute from overflowing the stack.
+    pub max_attribute_depth: usize,
+}
 
-    Ok(ClassFile {
-        version: ClassFileVersion(major, minor),
-        constant_pool: constant_pool_items,
-    })
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions { max_major_version: None, max_utf8_len: u16::MAX, max_attribute_depth: 64 }
+    }
 }
 
-pub fn read_constant_pool_item<R>(mut buf_read: R) -> Result<ConstantPoolItem, Error>
-    where R: BufRead,
-{
-    let type_tag = buf_read.read_u8()?;
-    let type_tag = ConstantPoolItemTag::try_from(type_tag)?;
-    match type_tag {
-        ConstantPoolItemTag::Utf8 => {
-            let strlen = buf_read.read_u16()?;
-            let mut utf8_bytes = vec![0; strlen as usize];
-            buf_read.read_exact(&mut utf8_bytes)?;
+/// Cheaply check whether `bytes` looks like a class file, i.e. whether it starts with [`MAGIC`].
+pub fn is_classfile(bytes: &[u8]) -> bool {
+    bytes.starts_with(&MAGIC)
+}
 
-            Ok(ConstantPoolItem::Utf8(String::from_utf8(utf8_bytes)?))
-        }
-        ConstantPoolItemTag::Integer => {
-            Ok(ConstantPoolItem::Integer(buf_read.read_i32()?))
-        }
-        ConstantPoolItemTag::Float => {
-            Ok(ConstantPoolItem::Float(buf_read.read_f32()?))
-        }
-        ConstantPoolItemTag::Long => {
-            Ok(ConstantPoolItem::Long(buf_read.read_i64()?))
-        }
-        ConstantPoolItemTag::Double => {
-            Ok(ConstantPoolItem::Double(buf_read.read_f64()?))
-        }
-        ConstantPoolItemTag::Class => {
-            // TODO(aduffy): handle CONSTANT_Class_info
-            let _index = buf_read.read_u16()?;
+/// Read the first four bytes from `reader` and check that they are [`MAGIC`].
+pub fn verify_magic<R>(reader: &mut R) -> Result<(), Error>
+    where R: Read {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).eof_context("magic")?;
 
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::String => {
-            // TODO(aduffy): handle CONSTANT_String_info
-            let _string_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::FieldRef => {
-            // TODO(aduffy): handle CONSTANT_Fieldref_info
-            let _class_index = buf_read.read_u16()?;
-            let _name_and_type_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::MethodRef => {
-            // TODO(aduffy): handle CONSTANT_Methodref_info
-            let _class_index = buf_read.read_u16()?;
-            let _name_and_type_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::InterfaceMethodRef => {
-            // TODO(aduffy): handle CONSTANT_InterfaceMethodref_info
-            let _class_index = buf_read.read_u16()?;
-            let _name_and_type_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::NameAndType => {
-            // TODO(aduffy): handle CONSTANT_NameAndType_info
-            let _name_index = buf_read.read_u16()?;
-            let _descriptor_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::MethodHandle => {
-            // TODO(aduffy): handle CONSTANT_MethodHandle_info
-            let _reference_kind = buf_read.read_u8()?;
-            let _reference_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::MethodType => {
-            // TODO(aduffy): handle CONSTANT_MethodType_info
-            let _descriptor_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
-        ConstantPoolItemTag::InvokeDynamic => {
-            // TODO(aduffy): handle CONSTANT_InvokeDynamic_info
-            let _bootstrap_method_attr_index = buf_read.read_u16()?;
-            let _name_and_type_index = buf_read.read_u16()?;
-            Ok(ConstantPoolItem::Unsupported)
-        }
+    if MAGIC != magic {
+        return Err(Error::InvalidMagic(magic));
     }
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod test {
-    use std::io::Write;
-    use std::net::{SocketAddr, TcpListener, TcpStream};
+pub fn read_from<R>(reader: R) -> Result<ClassFile, Error>
+    where R: Read {
+    read_from_buffered(BufReader::new(reader))
+}
 
-    use bytes::{Buf, Bytes};
+/// Like [`read_from`], but reads straight from a reader that is already buffered, such as a
+/// `BufReader` or a `&[u8]`, instead of wrapping it in another buffer.
+///
+/// This consumes exactly the bytes of the class file, so passing `&mut reader` leaves `reader`
+/// positioned at whatever follows, ready to read the next of several concatenated classes:
+///
+/// ```
+/// use std::io::{BufReader, Cursor};
+///
+/// let hello = std::fs::read("tests/fixtures/Hello.class")?;
+/// let mut reader = BufReader::new(Cursor::new([hello.as_slice(), &hello].concat()));
+///
+/// let first = rusty_classfile::read_from_buffered(&mut reader)?;
+/// let second = rusty_classfile::read_from_buffered(&mut reader)?;
+/// assert_eq!(first, second);
+/// assert_eq!(reader.into_inner().position() as usize, 2 * hello.len());
+/// # Ok::<(), rusty_classfile::Error>(())
+/// ```
+pub fn read_from_buffered<R>(reader: R) -> Result<ClassFile, Error>
+    where R: BufRead {
+    read_class(&mut CountingReader::new(reader), ParseOptions::default())
+}
+
+/// Parse and [validate](ClassFile::validate) a class file held in memory, for use as a fuzz
+/// target. Any input, however malformed, produces an `Err` rather than a panic. The counts and
+/// lengths declared in the input only size an allocation as far as the rest of the input could
+/// actually hold, here and in the typed attribute accessors.
+///
+/// Because of the validation, this is stricter than [`ClassFile::from_bytes`]: a class that
+/// parses but refers to missing or mistyped constant pool entries is an `Err` here.
+pub fn parse_bytes(bytes: &[u8]) -> Result<ClassFile, Error> {
+    let class_file = read_from_buffered(bytes)?;
+    class_file.validate()?;
+
+    Ok(class_file)
+}
+
+/// Like [`read_from`], but with control over how malformed input is handled.
+pub fn read_from_with_options<R>(reader: R, options: ParseOptions) -> Result<ClassFile, Error>
+    where R: Read {
+    read_class(&mut CountingReader::new(BufReader::new(reader)), options)
+}
+
+/// Like [`read_from`], but also returns the number of bytes that made up the class file.
+///
+/// Any data following the class file is left unparsed, though some of it may have been read from
+/// `reader` into an internal buffer. To carry on reading after the class from a seekable reader,
+/// seek to the position it started at plus the returned count, or use [`read_from_buffered`] with
+/// a buffer you keep.
+pub fn read_from_counted<R>(reader: R) -> Result<(ClassFile, usize), Error>
+    where R: Read {
+    let mut counting = CountingReader::new(BufReader::new(reader));
+    let class = read_class(&mut counting, ParseOptions::default())?;
+
+    Ok((class, counting.count()))
+}
+
+/// Read class files laid back-to-back in `reader` until it runs out.
+///
+/// Each class ends right where its last attribute does, so the next one is read from the
+/// following byte. Running out of input between classes ends the sequence, but running out in the
+/// middle of one is an error.
+pub fn read_all_from<R>(reader: R) -> Result<Vec<ClassFile>, Error>
+    where R: Read {
+    let mut counting = CountingReader::new(BufReader::new(reader));
+    let mut classes = Vec::new();
+    while !counting.fill_buf()?.is_empty() {
+        classes.push(read_class(&mut counting, ParseOptions::default())?);
+    }
+
+    Ok(classes)
+}
+
+/// What [`read_from_partial`] managed to parse before running into an error.
+#[derive(Debug)]
+pub struct PartialClassFile {
+    /// The class file version, if the header was read.
+    pub version: Option<ClassFileVersion>,
+    /// The `constant_pool_count` from the header, which is one more than the number of constant
+    /// pool slots, if it was read.
+    pub constant_pool_count: Option<u16>,
+    /// Every constant pool entry read before the error. If the error came after the constant
+    /// pool, this is the complete pool.
+    pub constant_pool: ConstantPool,
+    /// The error that stopped parsing.
+    pub error: Error,
+}
+
+/// Like [`read_from`], but if parsing fails, returns whatever was parsed up to that point along
+/// with the error. This is useful for digging into truncated or corrupt files.
+pub fn read_from_partial<R>(reader: R) -> Result<ClassFile, PartialClassFile>
+    where R: Read {
+    let mut buf_read = CountingReader::new(BufReader::new(reader));
+    let mut progress = Progress::default();
+    read_class_with_progress(&mut buf_read, ParseOptions::default(), &mut progress)
+        .map_err(|error| PartialClassFile {
+            version: progress.version,
+            constant_pool_count: progress.constant_pool_count,
+            constant_pool: ConstantPool::from(progress.constant_pool_items),
+            error,
+        })
+}
+
+/// The parts of a class read so far, kept outside of [`read_class_with_progress`] so that they
+/// survive an error.
+#[derive(Default)]
+struct Progress {
+    version: Option<ClassFileVersion>,
+    constant_pool_count: Option<u16>,
+    constant_pool_items: Vec<ConstantPoolItem>,
+}
+
+fn read_class<R>(buf_read: &mut CountingReader<R>, options: ParseOptions) -> Result<ClassFile, Error>
+    where R: BufRead {
+    read_class_with_progress(buf_read, options, &mut Progress::default())
+}
+
+fn read_class_with_progress<R>(
+    buf_read: &mut CountingReader<R>,
+    options: ParseOptions,
+    progress: &mut Progress,
+) -> Result<ClassFile, Error>
+    where R: BufRead {
+    let version = read_version(buf_read, &options)?;
+    progress.version = Some(version);
+
+    let raw_constant_pool_count = buf_read.read_u16().eof_context("constant pool")?;
+    progress.constant_pool_count = Some(raw_constant_pool_count);
+    let constant_pool_items = &mut progress.constant_pool_items;
+    // The smallest entries, such as a Class or an empty Utf8, are 3 bytes.
+    let constant_pool_count = raw_constant_pool_count.saturating_sub(1);
+    constant_pool_items.reserve(capacity_for(&mut *buf_read, constant_pool_count, 3).eof_context("constant pool")?);
+    read_constant_pool_entries(buf_read, raw_constant_pool_count, &options, |_, item| constant_pool_items.push(item))?;
+
+    let ClassBody { access_flags, this_class, super_class, interfaces, fields, methods, attributes } =
+        read_class_body(buf_read)?;
+
+    Ok(ClassFile {
+        version,
+        constant_pool: ConstantPool::from(std::mem::take(&mut progress.constant_pool_items)),
+        access_flags,
+        this_class,
+        super_class,
+        interfaces,
+        fields,
+        methods,
+        attributes,
+    })
+}
+
+/// Read the magic and version that start every class file, and check the version against
+/// `options.max_major_version`.
+pub(crate) fn read_version<R>(reader: &mut R, options: &ParseOptions) -> Result<ClassFileVersion, Error>
+    where R: Read {
+    verify_magic(reader)?;
+
+    // Read major and minor versions
+    let minor = reader.read_u16().eof_context("version")?;
+    let major = reader.read_u16().eof_context("version")?;
+    if let Some(max) = options.max_major_version.filter(|&max| major > max) {
+        return Err(Error::UnsupportedVersion { major, minor, max });
+    }
+
+    Ok(ClassFileVersion(major, minor))
+}
+
+/// A constant pool entry as read by one of the parsers, which only needs to know how many slots
+/// it takes.
+pub(crate) trait ConstantPoolEntry {
+    fn is_8byte(&self) -> bool;
+}
+
+impl ConstantPoolEntry for ConstantPoolItem {
+    fn is_8byte(&self) -> bool {
+        ConstantPoolItem::is_8byte(self)
+    }
+}
+
+/// Read the entries of a constant pool whose `constant_pool_count` has just been read, passing
+/// each one to `on_item` along with its JVM index. Unknown tags are reported at their offset in
+/// the class file, counted from wherever `buf_read` started.
+pub(crate) fn read_constant_pool_entries<R, F>(
+    buf_read: &mut CountingReader<R>,
+    constant_pool_count: u16,
+    options: &ParseOptions,
+    on_item: F,
+) -> Result<(), Error>
+    where R: BufRead,
+          F: FnMut(u16, ConstantPoolItem),
+{
+    let read_item = |reader: &mut CountingReader<R>, options: &ParseOptions| {
+        read_constant_pool_item_limited(reader, options.max_utf8_len)
+    };
+
+    read_constant_pool_entries_with(buf_read, constant_pool_count, options, read_item, on_item)
+}
+
+/// Like [`read_constant_pool_entries`], but reading each entry with `read_item`, so that a parser
+/// can produce its own kind of entry while sharing the slot accounting and error reporting.
+pub(crate) fn read_constant_pool_entries_with<R, T, F, G>(
+    buf_read: &mut CountingReader<R>,
+    constant_pool_count: u16,
+    options: &ParseOptions,
+    mut read_item: F,
+    mut on_item: G,
+) -> Result<(), Error>
+    where R: BufRead,
+          T: ConstantPoolEntry,
+          F: FnMut(&mut CountingReader<R>, &ParseOptions) -> Result<T, Error>,
+          G: FnMut(u16, T),
+{
+    // NOTE: For some reason the JVM stores this as N+1, and uses 1-based indexing for items.
+    // A count of 0 isn't something javac produces, but treat it the same as an empty pool.
+    let constant_pool_count = constant_pool_count.saturating_sub(1);
+    let mut constant_pool_index = 0;
+    while constant_pool_index < constant_pool_count {
+        let offset = buf_read.count() as u64;
+        let item = match read_item(buf_read, options) {
+            Err(Error::InvalidConstantPoolItemTag { tag, .. }) => {
+                return Err(Error::InvalidConstantPoolItemTag { tag, offset });
+            }
+            item => item.eof_context("constant pool")?,
+        };
+        let index = constant_pool_index + 1;
+        // JVM oddity: 64-bit types occupy 2 slots in the constant pool.
+        if item.is_8byte() {
+            constant_pool_index += 2
+        } else {
+            constant_pool_index += 1
+        }
+
+        on_item(index, item);
+    }
+
+    Ok(())
+}
+
+/// Everything in a class file after the constant pool.
+pub(crate) struct ClassBody {
+    pub(crate) access_flags: ClassAccessFlags,
+    pub(crate) this_class: u16,
+    pub(crate) super_class: u16,
+    pub(crate) interfaces: Vec<u16>,
+    pub(crate) fields: Vec<FieldInfo>,
+    pub(crate) methods: Vec<MethodInfo>,
+    pub(crate) attributes: Vec<AttributeInfo>,
+}
+
+pub(crate) fn read_class_body<R>(mut buf_read: R) -> Result<ClassBody, Error>
+    where R: BufRead {
+    let access_flags = ClassAccessFlags::from_bits_retain(buf_read.read_u16().eof_context("access flags")?);
+    let this_class = buf_read.read_u16().eof_context("this_class")?;
+    let super_class = buf_read.read_u16().eof_context("super_class")?;
+    let interfaces = read_interfaces(&mut buf_read)?;
+
+    let fields_count = buf_read.read_u16().eof_context("fields")?;
+    // The flags, name, descriptor and attributes count of a member make up 8 bytes.
+    let mut fields = Vec::with_capacity(capacity_for(&mut buf_read, fields_count, 8).eof_context("fields")?);
+    for _ in 0..fields_count {
+        fields.push(read_field_info(&mut buf_read).eof_context("fields")?);
+    }
+
+    let methods_count = buf_read.read_u16().eof_context("methods")?;
+    let mut methods = Vec::with_capacity(capacity_for(&mut buf_read, methods_count, 8).eof_context("methods")?);
+    for _ in 0..methods_count {
+        methods.push(read_method_info(&mut buf_read).eof_context("methods")?);
+    }
+
+    let attributes = read_attributes(&mut buf_read).eof_context("attributes")?;
+
+    Ok(ClassBody { access_flags, this_class, super_class, interfaces, fields, methods, attributes })
+}
+
+/// Read the interfaces count and the indices that follow it.
+pub(crate) fn read_interfaces<R>(mut buf_read: R) -> Result<Vec<u16>, Error>
+    where R: BufRead {
+    let interfaces_count = buf_read.read_u16().eof_context("interfaces")?;
+    let mut interfaces = Vec::with_capacity(capacity_for(&mut buf_read, interfaces_count, 2).eof_context("interfaces")?);
+    for _ in 0..interfaces_count {
+        interfaces.push(buf_read.read_u16().eof_context("interfaces")?);
+    }
+
+    Ok(interfaces)
+}
+
+pub(crate) fn read_field_info<R>(mut buf_read: R) -> Result<FieldInfo, Error>
+    where R: BufRead,
+{
+    let access_flags = FieldAccessFlags::from_bits_retain(buf_read.read_u16()?);
+    let name_index = buf_read.read_u16()?;
+    let descriptor_index = buf_read.read_u16()?;
+    let attributes = read_attributes(&mut buf_read)?;
+
+    Ok(FieldInfo {
+        access_flags,
+        name_index,
+        descriptor_index,
+        attributes,
+    })
+}
+
+pub(crate) fn read_method_info<R>(mut buf_read: R) -> Result<MethodInfo, Error>
+    where R: BufRead,
+{
+    let access_flags = MethodAccessFlags::from_bits_retain(buf_read.read_u16()?);
+    let name_index = buf_read.read_u16()?;
+    let descriptor_index = buf_read.read_u16()?;
+    let attributes = read_attributes(&mut buf_read)?;
+
+    Ok(MethodInfo {
+        access_flags,
+        name_index,
+        descriptor_index,
+        attributes,
+    })
+}
+
+/// Read `len` bytes into a new buffer. The buffer grows with the data actually read rather than
+/// being allocated up front, so a corrupt length in a truncated file can't force a huge allocation.
+pub(crate) fn read_bytes<R>(reader: R, len: u32) -> Result<Vec<u8>, std::io::Error>
+    where R: Read {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < len as usize {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(bytes)
+}
+
+/// How much room to reserve up front for `count` items that each take at least `min_size` bytes.
+/// A corrupt count can't be caught up front in general, but when the rest of the input is already
+/// buffered, as it is for a slice, don't reserve room for more items than it could hold.
+pub(crate) fn capacity_for<R>(mut reader: R, count: u16, min_size: usize) -> Result<usize, std::io::Error>
+    where R: BufRead {
+    let available = reader.fill_buf()?.len() / min_size;
+
+    Ok(available.min(count as usize))
+}
+
+/// Read a single constant pool entry, tag included. This needs no buffering, so it can be used
+/// directly on a slice or a [`Cursor`](std::io::Cursor).
+pub fn read_constant_pool_item<R>(reader: R) -> Result<ConstantPoolItem, Error>
+    where R: Read {
+    read_constant_pool_item_limited(reader, u16::MAX)
+}
+
+fn read_constant_pool_item_limited<R>(mut reader: R, max_utf8_len: u16) -> Result<ConstantPoolItem, Error>
+    where R: Read {
+    let type_tag = reader.read_u8()?;
+    let type_tag = ConstantPoolItemTag::try_from(type_tag)?;
+    match type_tag {
+        ConstantPoolItemTag::Utf8 => {
+            let strlen = reader.read_u16()?;
+            if strlen > max_utf8_len {
+                return Err(Error::Utf8TooLong { length: strlen, max: max_utf8_len });
+            }
+            let utf8_bytes = read_bytes(&mut reader, strlen as u32)?;
+
+            Ok(ConstantPoolItem::Utf8(decode_modified_utf8(&utf8_bytes)?))
+        }
+        ConstantPoolItemTag::Integer => {
+            Ok(ConstantPoolItem::Integer(reader.read_i32()?))
+        }
+        ConstantPoolItemTag::Float => {
+            Ok(ConstantPoolItem::Float(reader.read_f32()?))
+        }
+        ConstantPoolItemTag::Long => {
+            Ok(ConstantPoolItem::Long(reader.read_i64()?))
+        }
+        ConstantPoolItemTag::Double => {
+            Ok(ConstantPoolItem::Double(reader.read_f64()?))
+        }
+        ConstantPoolItemTag::Class => {
+            let name_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::Class { name_index })
+        }
+        ConstantPoolItemTag::String => {
+            let string_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::String { string_index })
+        }
+        ConstantPoolItemTag::FieldRef => {
+            let class_index = reader.read_u16()?;
+            let name_and_type_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::FieldRef { class_index, name_and_type_index })
+        }
+        ConstantPoolItemTag::MethodRef => {
+            let class_index = reader.read_u16()?;
+            let name_and_type_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::MethodRef { class_index, name_and_type_index })
+        }
+        ConstantPoolItemTag::InterfaceMethodRef => {
+            let class_index = reader.read_u16()?;
+            let name_and_type_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index })
+        }
+        ConstantPoolItemTag::NameAndType => {
+            let name_index = reader.read_u16()?;
+            let descriptor_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::NameAndType { name_index, descriptor_index })
+        }
+        ConstantPoolItemTag::MethodHandle => {
+            let reference_kind = ReferenceKind::try_from(reader.read_u8()?)?;
+            let reference_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::MethodHandle { reference_kind, reference_index })
+        }
+        ConstantPoolItemTag::MethodType => {
+            let descriptor_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::MethodType { descriptor_index })
+        }
+        ConstantPoolItemTag::Dynamic => {
+            let bootstrap_method_attr_index = reader.read_u16()?;
+            let name_and_type_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::Dynamic { bootstrap_method_attr_index, name_and_type_index })
+        }
+        ConstantPoolItemTag::InvokeDynamic => {
+            let bootstrap_method_attr_index = reader.read_u16()?;
+            let name_and_type_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::InvokeDynamic { bootstrap_method_attr_index, name_and_type_index })
+        }
+        ConstantPoolItemTag::Module => {
+            let name_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::Module { name_index })
+        }
+        ConstantPoolItemTag::Package => {
+            let name_index = reader.read_u16()?;
+            Ok(ConstantPoolItem::Package { name_index })
+        }
+    }
+}
+
+/// Serialize a `ClassFile` in the class file format, such that `read_from` would read it back.
+pub fn write_to<W>(class: &ClassFile, writer: W) -> Result<(), Error>
+    where W: Write {
+    let mut buf_write = BufWriter::new(writer);
+
+    buf_write.write_all(&MAGIC)?;
+    buf_write.write_u16(class.version.minor())?;
+    buf_write.write_u16(class.version.major())?;
+
+    // The count is stored as N+1, so a full pool of u16::MAX slots is already too big.
+    buf_write.write_count(class.constant_pool_slot_count() as usize + 1)?;
+    for item in class.constant_pool.items() {
+        write_constant_pool_item(&mut buf_write, item)?;
+    }
+
+    buf_write.write_u16(class.access_flags.bits())?;
+    buf_write.write_u16(class.this_class)?;
+    buf_write.write_u16(class.super_class)?;
+
+    buf_write.write_count(class.interfaces.len())?;
+    for &interface in &class.interfaces {
+        buf_write.write_u16(interface)?;
+    }
+
+    buf_write.write_count(class.fields.len())?;
+    for field in &class.fields {
+        buf_write.write_u16(field.access_flags.bits())?;
+        buf_write.write_u16(field.name_index)?;
+        buf_write.write_u16(field.descriptor_index)?;
+        write_attributes(&mut buf_write, &field.attributes)?;
+    }
+
+    buf_write.write_count(class.methods.len())?;
+    for method in &class.methods {
+        buf_write.write_u16(method.access_flags.bits())?;
+        buf_write.write_u16(method.name_index)?;
+        buf_write.write_u16(method.descriptor_index)?;
+        write_attributes(&mut buf_write, &method.attributes)?;
+    }
+
+    write_attributes(&mut buf_write, &class.attributes)?;
+
+    buf_write.flush()?;
+    Ok(())
+}
+
+pub fn write_constant_pool_item<W>(mut buf_write: W, item: &ConstantPoolItem) -> Result<(), Error>
+    where W: Write,
+{
+    if let ConstantPoolItem::Unsupported { tag, ref raw } = *item {
+        buf_write.write_u8(tag)?;
+        buf_write.write_all(raw)?;
+        return Ok(());
+    }
+
+    let tag = item.tag().expect("only unsupported entries have no tag");
+    buf_write.write_u8(tag as u8)?;
+
+    match *item {
+        ConstantPoolItem::Utf8(ref value) => {
+            let encoded = encode_modified_utf8(value);
+            let strlen = u16::try_from(encoded.len())
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "Utf8 entry is longer than 65535 bytes"))?;
+            buf_write.write_u16(strlen)?;
+            buf_write.write_all(&encoded)?;
+        }
+        ConstantPoolItem::Integer(value) => buf_write.write_i32(value)?,
+        ConstantPoolItem::Float(value) => buf_write.write_f32(value)?,
+        ConstantPoolItem::Long(value) => buf_write.write_i64(value)?,
+        ConstantPoolItem::Double(value) => buf_write.write_f64(value)?,
+        ConstantPoolItem::Class { name_index }
+        | ConstantPoolItem::Module { name_index }
+        | ConstantPoolItem::Package { name_index } => buf_write.write_u16(name_index)?,
+        ConstantPoolItem::String { string_index } => buf_write.write_u16(string_index)?,
+        ConstantPoolItem::FieldRef { class_index, name_and_type_index }
+        | ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+        | ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index } => {
+            buf_write.write_u16(class_index)?;
+            buf_write.write_u16(name_and_type_index)?;
+        }
+        ConstantPoolItem::NameAndType { name_index, descriptor_index } => {
+            buf_write.write_u16(name_index)?;
+            buf_write.write_u16(descriptor_index)?;
+        }
+        ConstantPoolItem::MethodHandle { reference_kind, reference_index } => {
+            buf_write.write_u8(reference_kind as u8)?;
+            buf_write.write_u16(reference_index)?;
+        }
+        ConstantPoolItem::MethodType { descriptor_index } => buf_write.write_u16(descriptor_index)?,
+        ConstantPoolItem::Dynamic { bootstrap_method_attr_index, name_and_type_index }
+        | ConstantPoolItem::InvokeDynamic { bootstrap_method_attr_index, name_and_type_index } => {
+            buf_write.write_u16(bootstrap_method_attr_index)?;
+            buf_write.write_u16(name_and_type_index)?;
+        }
+        ConstantPoolItem::Unsupported { .. } => unreachable!("unsupported entries are written above"),
+    }
+
+    Ok(())
+}
+
+fn write_attributes<W>(mut buf_write: W, attributes: &[AttributeInfo]) -> Result<(), Error>
+    where W: Write,
+{
+    buf_write.write_count(attributes.len())?;
+    for attribute in attributes {
+        let length = u32::try_from(attribute.info.len())
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "attribute is longer than 4GiB"))?;
+        buf_write.write_u16(attribute.name_index)?;
+        buf_write.write_u32(length)?;
+        buf_write.write_all(&attribute.info)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+
+    use bytes::{Buf, Bytes};
+
+    use crate::{AttributeInfo, capacity_for, ClassAccessFlags, ClassFile, ClassFileBuilder, ClassFileVersion, ConstantPool, ConstantPoolItem, ConstantPoolItemTag, Error, FieldAccessFlags, FieldInfo, is_classfile, MAGIC, MethodAccessFlags, MethodInfo, MethodRefInfo, ParseOptions, parse_bytes, read_all_from, read_constant_pool_item, read_from, read_from_partial, read_from_buffered, read_from_counted, read_from_with_options, ReadExt, ReferenceKind, verify_magic, write_constant_pool_item, write_to};
+
+    /// Everything following the constant pool in a class with no members.
+    const EMPTY_BODY: &[u8] = &[0u8; 14];
+
+    /// Assemble the bytes of a version 10.10 class file from raw constant pool entries, followed by `body`.
+    fn class_bytes(constant_pool: &[Vec<u8>], body: &[u8]) -> Vec<u8> {
+        // Long and Double entries take up two slots.
+        let slots = constant_pool.iter()
+            .map(|entry| if entry[0] == 5 || entry[0] == 6 { 2 } else { 1 })
+            .sum::<u16>();
+
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0u8, 10u8, 0u8, 10u8];
+        bytes.extend_from_slice(&(slots + 1).to_be_bytes());
+        for entry in constant_pool {
+            bytes.extend_from_slice(entry);
+        }
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    fn utf8_entry(value: &str) -> Vec<u8> {
+        let mut entry = vec![1u8];
+        entry.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        entry.extend_from_slice(value.as_bytes());
+        entry
+    }
+
+    #[test]
+    fn test_invalid_magic() {
+        let bytes_reader = Bytes::from_static(&[0u8, 0u8, 0u8, 0u8]);
+        let result = read_from(bytes_reader.reader());
+        assert!(matches!(result.unwrap_err(), Error::InvalidMagic([0u8, 0u8, 0u8, 0u8])));
+    }
+
+    #[test]
+    fn test_valid_magic() {
+        let bytes_reader = Bytes::from(class_bytes(&[], EMPTY_BODY));
+        let result = read_from(bytes_reader.reader());
+        assert_eq!(result.unwrap(), ClassFile {
+            version: ClassFileVersion(10, 10),
+            constant_pool: ConstantPool::default(),
+            access_flags: ClassAccessFlags::default(),
+            this_class: 0,
+            super_class: 0,
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            attributes: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_zero_constant_pool_count() {
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8];
+        bytes.extend_from_slice(EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert!(class_file.constant_pool.is_empty());
+    }
+
+    #[test]
+    fn test_entry_after_double() {
+        let double = [6u8, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[double.to_vec(), utf8_entry("after")], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(1), Some(&ConstantPoolItem::Double(1.5)));
+        assert_eq!(class_file.constant_pool.get(2), None);
+        assert_eq!(class_file.constant_pool.resolve_utf8(3), Some("after"));
+    }
+
+    #[test]
+    fn test_constant_pool_iter() {
+        let double = [6u8, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[utf8_entry("before"), double.to_vec(), utf8_entry("after")], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let entries: Vec<(u16, &ConstantPoolItem)> = class_file.constant_pool_iter().collect();
+        assert_eq!(entries, vec![
+            (1, &ConstantPoolItem::Utf8("before".to_string())),
+            (2, &ConstantPoolItem::Double(1.5)),
+            (4, &ConstantPoolItem::Utf8("after".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn test_class_info() {
+        let bytes = class_bytes(&[utf8_entry("java/lang/Object"), vec![7, 0, 1]], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let Some(&ConstantPoolItem::Class { name_index }) = class_file.constant_pool.get(2) else {
+            panic!("expected a Class entry, found {:?}", class_file.constant_pool.get(2));
+        };
+        assert_eq!(name_index, 1);
+        assert_eq!(class_file.constant_pool.resolve_utf8(name_index), Some("java/lang/Object"));
+        assert!(!class_file.constant_pool.get(2).unwrap().is_8byte());
+    }
+
+    #[test]
+    fn test_string_info() {
+        let bytes = class_bytes(&[utf8_entry("hello"), vec![8, 0, 1]], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let Some(&ConstantPoolItem::String { string_index }) = class_file.constant_pool.get(2) else {
+            panic!("expected a String entry, found {:?}", class_file.constant_pool.get(2));
+        };
+        assert_eq!(string_index, 1);
+        assert_eq!(class_file.constant_pool.resolve_utf8(string_index), Some("hello"));
+    }
+
+    #[test]
+    fn test_field_ref_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("Main"),
+            vec![7, 0, 1],
+            utf8_entry("x"),
+            utf8_entry("I"),
+            vec![12, 0, 3, 0, 4],
+            vec![9, 0, 2, 0, 5],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(6), Some(&ConstantPoolItem::FieldRef { class_index: 2, name_and_type_index: 5 }));
+    }
+
+    #[test]
+    fn test_method_ref_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("java/lang/Object"),
+            vec![7, 0, 1],
+            utf8_entry("<init>"),
+            utf8_entry("()V"),
+            vec![12, 0, 3, 0, 4],
+            vec![10, 0, 2, 0, 5],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(6), Some(&ConstantPoolItem::MethodRef { class_index: 2, name_and_type_index: 5 }));
+    }
+
+    #[test]
+    fn test_interface_method_ref_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("java/util/List"),
+            vec![7, 0, 1],
+            utf8_entry("size"),
+            utf8_entry("()I"),
+            vec![12, 0, 3, 0, 4],
+            vec![11, 0, 2, 0, 5],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let item = class_file.constant_pool.get(6).unwrap();
+        assert_eq!(*item, ConstantPoolItem::InterfaceMethodRef { class_index: 2, name_and_type_index: 5 });
+        assert_eq!(item.ref_indices(), Some((2, 5)));
+    }
+
+    #[test]
+    fn test_ref_indices() {
+        assert_eq!(ConstantPoolItem::FieldRef { class_index: 1, name_and_type_index: 2 }.ref_indices(), Some((1, 2)));
+        assert_eq!(ConstantPoolItem::MethodRef { class_index: 3, name_and_type_index: 4 }.ref_indices(), Some((3, 4)));
+        assert_eq!(ConstantPoolItem::Class { name_index: 1 }.ref_indices(), None);
+    }
+
+    #[test]
+    fn test_name_and_type_info() {
+        let bytes = class_bytes(&[utf8_entry("<init>"), utf8_entry("()V"), vec![12, 0, 1, 0, 2]], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let Some(&ConstantPoolItem::NameAndType { name_index, descriptor_index }) = class_file.constant_pool.get(3) else {
+            panic!("expected a NameAndType entry, found {:?}", class_file.constant_pool.get(3));
+        };
+        assert_eq!(class_file.constant_pool.resolve_utf8(name_index), Some("<init>"));
+        assert_eq!(class_file.constant_pool.resolve_utf8(descriptor_index), Some("()V"));
+    }
+
+    #[test]
+    fn test_method_handle_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("java/lang/Object"),
+            vec![7, 0, 1],
+            utf8_entry("<init>"),
+            utf8_entry("()V"),
+            vec![12, 0, 3, 0, 4],
+            vec![10, 0, 2, 0, 5],
+            vec![15, 8, 0, 6],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(7), Some(&ConstantPoolItem::MethodHandle {
+            reference_kind: ReferenceKind::NewInvokeSpecial,
+            reference_index: 6,
+        }));
+    }
+
+    #[test]
+    fn test_invalid_reference_kind() {
+        let bytes = class_bytes(&[vec![15, 10, 0, 1]], EMPTY_BODY);
+        let result = read_from(bytes.as_slice());
+        assert!(matches!(result.unwrap_err(), Error::InvalidReferenceKind(10)));
+    }
+
+    #[test]
+    fn test_method_type_info() {
+        let bytes = class_bytes(&[utf8_entry("()Ljava/lang/Object;"), vec![16, 0, 1]], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let Some(&ConstantPoolItem::MethodType { descriptor_index }) = class_file.constant_pool.get(2) else {
+            panic!("expected a MethodType entry, found {:?}", class_file.constant_pool.get(2));
+        };
+        assert_eq!(class_file.constant_pool.resolve_utf8(descriptor_index), Some("()Ljava/lang/Object;"));
+    }
+
+    #[test]
+    fn test_invoke_dynamic_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("run"),
+            utf8_entry("()Ljava/lang/Runnable;"),
+            vec![12, 0, 1, 0, 2],
+            vec![18, 0, 7, 0, 3],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(4), Some(&ConstantPoolItem::InvokeDynamic {
+            bootstrap_method_attr_index: 7,
+            name_and_type_index: 3,
+        }));
+    }
+
+    #[test]
+    fn test_dynamic_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("_"),
+            utf8_entry("Ljava/lang/Object;"),
+            vec![12, 0, 1, 0, 2],
+            vec![17, 0, 0, 0, 3],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(4), Some(&ConstantPoolItem::Dynamic {
+            bootstrap_method_attr_index: 0,
+            name_and_type_index: 3,
+        }));
+    }
+
+    #[test]
+    fn test_module_info() {
+        let bytes = class_bytes(&[
+            utf8_entry("module-info"),
+            vec![7, 0, 1],
+            utf8_entry("com.example.app"),
+            vec![19, 0, 3],
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let Some(&ConstantPoolItem::Module { name_index }) = class_file.constant_pool.get(4) else {
+            panic!("expected a Module entry, found {:?}", class_file.constant_pool.get(4));
+        };
+        assert_eq!(class_file.constant_pool.resolve_utf8(name_index), Some("com.example.app"));
+    }
+
+    #[test]
+    fn test_package_info() {
+        let bytes = class_bytes(&[utf8_entry("com/example/app"), vec![20, 0, 1]], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.get(2), Some(&ConstantPoolItem::Package { name_index: 1 }));
+    }
+
+    #[test]
+    fn test_fields() {
+        // class Main { int x; }
+        let body = [
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1, // fields_count
+            0, 0, 0, 1, 0, 2, 0, 0,
+            0, 0, // methods_count
+            0, 0, // attributes_count
+        ];
+        let bytes = class_bytes(&[utf8_entry("x"), utf8_entry("I")], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.fields, vec![FieldInfo {
+            access_flags: FieldAccessFlags::default(),
+            name_index: 1,
+            descriptor_index: 2,
+            attributes: Vec::new(),
+        }]);
+    }
+
+    #[test]
+    fn test_constant_value() {
+        // class Main { static final int X = 42; }
+        let body = [
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1, // fields_count
+            0, 0x18, 0, 1, 0, 2, 0, 1,
+            0, 3, 0, 0, 0, 2, 0, 4,
+            0, 0, // methods_count
+            0, 0, // attributes_count
+        ];
+        let bytes = class_bytes(&[
+            utf8_entry("X"),
+            utf8_entry("I"),
+            utf8_entry("ConstantValue"),
+            vec![3, 0, 0, 0, 42],
+        ], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.fields[0].constant_value(&class_file.constant_pool), Some(&ConstantPoolItem::Integer(42)));
+    }
+
+    #[test]
+    fn test_constant_value_fixture() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Hello.class").as_slice()).unwrap();
+        let values: Vec<Option<&ConstantPoolItem>> = class_file.fields.iter()
+            .map(|field| field.constant_value(&class_file.constant_pool))
+            .collect();
+
+        // ANSWER is a constant, ratio is an instance field with an initializer.
+        assert_eq!(values, vec![Some(&ConstantPoolItem::Long(42)), None]);
+    }
+
+    #[test]
+    fn test_bootstrap_methods_fixture() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Hello.class").as_slice()).unwrap();
+        let bootstrap_methods = class_file.bootstrap_methods().unwrap();
+
+        // One for the lambda and one for the string concatenation.
+        assert_eq!(bootstrap_methods.len(), 2);
+        assert_eq!(bootstrap_methods[0].arguments.len(), 3);
+        assert!(matches!(
+            class_file.constant_pool.get(bootstrap_methods[0].method_ref),
+            Some(ConstantPoolItem::MethodHandle { reference_kind: ReferenceKind::InvokeStatic, .. }),
+        ));
+    }
+
+    #[test]
+    fn test_methods() {
+        // class Main { public static void main(String[] args) {} }, minus the Code attribute.
+        let body = [
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, // fields_count
+            0, 1, // methods_count
+            0, 9, 0, 1, 0, 2, 0, 0,
+            0, 0, // attributes_count
+        ];
+        let bytes = class_bytes(&[utf8_entry("main"), utf8_entry("([Ljava/lang/String;)V")], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.methods, vec![MethodInfo {
+            access_flags: MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+            name_index: 1,
+            descriptor_index: 2,
+            attributes: Vec::new(),
+        }]);
+        let name_index = class_file.methods[0].name_index;
+        assert_eq!(class_file.constant_pool.resolve_utf8(name_index), Some("main"));
+    }
+
+    #[test]
+    fn test_interfaces() {
+        // class Main implements Runnable, Cloneable {}
+        let body = [
+            0, 0, 0, 0, 0, 0,
+            0, 2, // interfaces_count
+            0, 2, 0, 4,
+            0, 0, // fields_count
+            0, 0, // methods_count
+            0, 0, // attributes_count
+        ];
+        let bytes = class_bytes(&[
+            utf8_entry("java/lang/Runnable"),
+            vec![7, 0, 1],
+            utf8_entry("java/lang/Cloneable"),
+            vec![7, 0, 3],
+        ], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.interfaces, vec![2, 4]);
+        assert!(class_file.fields.is_empty());
+        assert!(class_file.methods.is_empty());
+    }
+
+    #[test]
+    fn test_attributes() {
+        let body = [
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, // fields_count
+            0, 0, // methods_count
+            0, 1, // attributes_count
+            0, 1, 0, 0, 0, 2, 0, 2,
+        ];
+        let bytes = class_bytes(&[utf8_entry("SourceFile"), utf8_entry("Main.java")], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.attributes, vec![AttributeInfo {
+            name_index: 1,
+            info: vec![0, 2],
+        }]);
+        assert_eq!(class_file.source_file(), Some("Main.java"));
+    }
+
+    #[test]
+    fn test_source_file_fixture() {
+        let class_file = read_from(include_bytes!("../tests/fixtures/Hello.class").as_slice()).unwrap();
+        assert_eq!(class_file.source_file(), Some("Hello.java"));
+    }
+
+    #[test]
+    fn test_class_header() {
+        // public final class Main extends java.lang.Object
+        let body = [
+            0x00, 0x31, // ACC_PUBLIC | ACC_FINAL | ACC_SUPER
+            0, 2,
+            0, 4,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let bytes = class_bytes(&[
+            utf8_entry("Main"),
+            vec![7, 0, 1],
+            utf8_entry("java/lang/Object"),
+            vec![7, 0, 3],
+        ], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.access_flags, ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL | ClassAccessFlags::SUPER);
+        assert!(class_file.access_flags.is_final());
+        assert_eq!(class_file.this_class, 2);
+        assert_eq!(class_file.super_class, 4);
+    }
+
+    #[test]
+    fn test_java_version() {
+        assert_eq!(ClassFileVersion(45, 3).java_version(), Some("Java 1.1"));
+        assert_eq!(ClassFileVersion(52, 0).java_version(), Some("Java 8"));
+        assert_eq!(ClassFileVersion(61, 0).java_version(), Some("Java 17"));
+        assert_eq!(ClassFileVersion(65, 0).java_version(), Some("Java 21"));
+        assert_eq!(ClassFileVersion(44, 0).java_version(), None);
+        assert_eq!(ClassFileVersion(200, 0).java_version(), None);
+    }
+
+    #[test]
+    fn test_version_accessors() {
+        let version = ClassFileVersion(61, 3);
+        assert_eq!(version.major(), 61);
+        assert_eq!(version.minor(), 3);
+    }
 
-    use crate::{ClassFile, ClassFileVersion, Error, read_from};
+    #[test]
+    fn test_is_preview() {
+        assert!(ClassFileVersion(61, 0xFFFF).is_preview());
+        assert!(ClassFileVersion(56, 0xFFFF).is_preview());
+        assert!(!ClassFileVersion(61, 0).is_preview());
+        // Before Java 12, a minor version of 0xFFFF is just a minor version.
+        assert!(!ClassFileVersion(51, 0xFFFF).is_preview());
+    }
 
     #[test]
-    fn test_invalid_magic() {
-        let bytes_reader = Bytes::from_static(&[0u8, 0u8, 0u8, 0u8]);
-        let result = read_from(bytes_reader.reader());
-        assert!(matches!(result.unwrap_err(), Error::InvalidMagic([0u8, 0u8, 0u8, 0u8])));
+    fn test_version_ordering() {
+        assert!(ClassFileVersion(52, 0) > ClassFileVersion(51, 65535));
+        assert!(ClassFileVersion(52, 1) > ClassFileVersion(52, 0));
+        assert!(ClassFileVersion(61, 0) >= ClassFileVersion(52, 0));
+        assert_eq!(ClassFileVersion(52, 0).cmp(&ClassFileVersion(52, 0)), std::cmp::Ordering::Equal);
     }
 
     #[test]
-    fn test_valid_magic() {
-        let bytes_reader = Bytes::from_static(&[0xCA, 0xFE, 0xBA, 0xBE, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8]);
-        let result = read_from(bytes_reader.reader());
-        assert_eq!(result.unwrap(), ClassFile {
+    fn test_validate() {
+        let body = [0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[utf8_entry("Main"), vec![7, 0, 1]], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert!(class_file.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_class_pointing_at_integer() {
+        let body = [0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[vec![3, 0, 0, 0, 42], vec![7, 0, 1]], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert!(matches!(class_file.validate().unwrap_err(), Error::InvalidConstantPoolReference {
+            index: 1,
+            expected: ConstantPoolItemTag::Utf8,
+        }));
+    }
+
+    #[test]
+    fn test_validate_method_handle_kind() {
+        // Builds a class whose #6 is a handle of `kind` pointing at a method ref at #5.
+        let handle = |kind: ReferenceKind, interface: bool| {
+            let mut builder = ClassFileBuilder::new(ClassFileVersion(61, 0));
+            let name = builder.add_utf8("Main");
+            let class_index = builder.add_class(name);
+            let method_name = builder.add_utf8("run");
+            let name_and_type_index = builder.add_name_and_type(method_name, name);
+            let target = builder.add_item(if interface {
+                ConstantPoolItem::InterfaceMethodRef { class_index, name_and_type_index }
+            } else {
+                ConstantPoolItem::MethodRef { class_index, name_and_type_index }
+            });
+            builder.add_item(ConstantPoolItem::MethodHandle { reference_kind: kind, reference_index: target });
+            builder.this_class(class_index).build().map(|_| ())
+        };
+
+        assert_eq!(handle(ReferenceKind::GetField, false), Err(Error::InvalidConstantPoolReference {
+            index: 5,
+            expected: ConstantPoolItemTag::FieldRef,
+        }));
+        assert_eq!(handle(ReferenceKind::InvokeVirtual, false), Ok(()));
+        assert_eq!(handle(ReferenceKind::InvokeInterface, false), Err(Error::InvalidConstantPoolReference {
+            index: 5,
+            expected: ConstantPoolItemTag::InterfaceMethodRef,
+        }));
+        assert_eq!(handle(ReferenceKind::InvokeInterface, true), Ok(()));
+        // Static interface methods can be the target of a handle, but InvokeVirtual needs a MethodRef.
+        assert_eq!(handle(ReferenceKind::InvokeStatic, true), Ok(()));
+        assert!(handle(ReferenceKind::InvokeVirtual, true).is_err());
+    }
+
+    #[test]
+    fn test_expected_ref_tag() {
+        assert_eq!(ReferenceKind::PutStatic.expected_ref_tag(), ConstantPoolItemTag::FieldRef);
+        assert_eq!(ReferenceKind::NewInvokeSpecial.expected_ref_tag(), ConstantPoolItemTag::MethodRef);
+        assert_eq!(ReferenceKind::InvokeInterface.expected_ref_tag(), ConstantPoolItemTag::InterfaceMethodRef);
+    }
+
+    #[test]
+    fn test_validate_phantom_slot_reference() {
+        // #1 is a Long, so #2 is unusable, yet the String at #3 refers to it.
+        let body = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[vec![5, 0, 0, 0, 0, 0, 0, 0, 1], vec![8, 0, 2]], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert!(matches!(class_file.validate().unwrap_err(), Error::ReferenceToWidePhantomSlot { index: 2 }));
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        let mut written = Vec::new();
+        write_to(&class_file, &mut written).unwrap();
+
+        assert_eq!(read_from(written.as_slice()).unwrap(), class_file);
+        assert_eq!(written, bytes);
+    }
+
+    #[test]
+    fn test_write_unsupported_entry() {
+        let class_file = ClassFile {
             version: ClassFileVersion(10, 10),
-            constant_pool: Vec::new(),
-        })
+            constant_pool: ConstantPool::from(vec![ConstantPoolItem::Unsupported { tag: 42, raw: vec![1, 2, 3] }]),
+            access_flags: ClassAccessFlags::default(),
+            this_class: 0,
+            super_class: 0,
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            attributes: Vec::new(),
+        };
+
+        let mut written = Vec::new();
+        write_to(&class_file, &mut written).unwrap();
+
+        assert_eq!(written, class_bytes(&[vec![42, 1, 2, 3]], EMPTY_BODY));
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let class_file = ClassFile::from_bytes(bytes).unwrap();
+
+        assert_eq!(class_file, read_from(bytes.as_slice()).unwrap());
+        assert!(matches!(ClassFile::from_bytes(&[0, 0, 0, 0]).unwrap_err(), Error::InvalidMagic(_)));
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/Hello.class");
+        let class_file = ClassFile::from_file(path).unwrap();
+
+        assert_eq!(class_file, ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap());
+        assert!(matches!(ClassFile::from_file("does/not/exist.class").unwrap_err(), Error::IoError(_)));
+    }
+
+    #[test]
+    fn test_read_from_counted() {
+        let class_bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let mut bytes = class_bytes.to_vec();
+        bytes.extend_from_slice(b"trailing data");
+
+        let (class_file, count) = read_from_counted(bytes.as_slice()).unwrap();
+        assert_eq!(count, class_bytes.len());
+        assert_eq!(class_file, read_from(class_bytes.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn test_read_u32() {
+        let mut bytes: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0xFF];
+        assert_eq!(bytes.read_u32().unwrap(), 0x00010203);
+        // Only a single byte is left, which isn't enough for another u32.
+        assert!(bytes.read_u32().is_err());
+    }
+
+    #[test]
+    fn test_invalid_tag_offset() {
+        // The header is 10 bytes and the Utf8 entry another 4, so the bad tag sits at byte 14.
+        let bytes = class_bytes(&[utf8_entry("a"), vec![2, 0, 0]], EMPTY_BODY);
+
+        let err = read_from(bytes.as_slice()).unwrap_err();
+        assert!(matches!(err, Error::InvalidConstantPoolItemTag { tag: 2, offset: 14 }), "{err:?}");
+    }
+
+    #[test]
+    fn test_unknown_tag_partial_constant_pool() {
+        // Overwrite the tag of the fifth entry of a real class, so that the entries after it can
+        // only be read if the unknown entry's size were known.
+        let mut bytes = include_bytes!("../tests/fixtures/Hello.class").to_vec();
+        let class_file = ClassFile::from_bytes(&bytes).unwrap();
+        let mut offset = 10;
+        for item in &class_file.constant_pool.items()[..4] {
+            let mut encoded = Vec::new();
+            write_constant_pool_item(&mut encoded, item).unwrap();
+            offset += encoded.len();
+        }
+        bytes[offset] = 2;
+
+        let partial = read_from_partial(bytes.as_slice()).unwrap_err();
+        assert_eq!(partial.error, Error::InvalidConstantPoolItemTag { tag: 2, offset: offset as u64 });
+        assert_eq!(partial.constant_pool.items(), &class_file.constant_pool.items()[..4]);
+    }
+
+    #[test]
+    fn test_max_major_version() {
+        // The pool holds an unknown tag, so this only parses if the version check stops it first.
+        let bytes = class_bytes(&[vec![2]], EMPTY_BODY);
+
+        let options = ParseOptions { max_major_version: Some(9), ..ParseOptions::default() };
+        let err = read_from_with_options(bytes.as_slice(), options).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedVersion { major: 10, minor: 10, max: 9 }), "{err:?}");
+
+        let bytes = class_bytes(&[utf8_entry("ok")], EMPTY_BODY);
+        let options = ParseOptions { max_major_version: Some(10), ..ParseOptions::default() };
+        assert!(read_from_with_options(bytes.as_slice(), options).is_ok());
+    }
+
+    #[test]
+    fn test_truncated_long_utf8() {
+        let mut bytes = class_bytes(&[], EMPTY_BODY);
+        bytes.truncate(8);
+        bytes.extend_from_slice(&[0, 2, 1, 0xFF, 0xFF, b'a', b'b']);
+
+        let err = read_from(bytes.as_slice()).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { context: "constant pool" }), "{err:?}");
+    }
+
+    #[test]
+    fn test_max_utf8_len() {
+        let bytes = class_bytes(&[utf8_entry("short"), utf8_entry("much longer")], EMPTY_BODY);
+
+        let options = ParseOptions { max_utf8_len: 5, ..ParseOptions::default() };
+        let err = read_from_with_options(bytes.as_slice(), options).unwrap_err();
+        assert!(matches!(err, Error::Utf8TooLong { length: 11, max: 5 }), "{err:?}");
+
+        let options = ParseOptions { max_utf8_len: 11, ..ParseOptions::default() };
+        assert!(read_from_with_options(bytes.as_slice(), options).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_tag_with_options() {
+        let bytes = class_bytes(&[vec![2], utf8_entry("after")], EMPTY_BODY);
+
+        let err = read_from_with_options(bytes.as_slice(), ParseOptions::default()).unwrap_err();
+        assert!(matches!(err, Error::InvalidConstantPoolItemTag { tag: 2, .. }));
+    }
+
+    #[test]
+    fn test_truncated_after_magic() {
+        let err = read_from(MAGIC.as_slice()).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { context: "version" }), "{err:?}");
+    }
+
+    #[test]
+    fn test_truncated_field() {
+        // A single field with its access flags but nothing else.
+        let body = [0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0];
+        let err = read_from(class_bytes(&[], &body).as_slice()).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { context: "fields" }), "{err:?}");
+    }
+
+    #[test]
+    fn test_read_from_buffered() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let buf_reader = std::io::BufReader::new(bytes.as_slice());
+
+        assert_eq!(read_from_buffered(buf_reader).unwrap(), read_from(bytes.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn test_method_exceptions() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Throws.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let method = |name| class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some(name))
+            .unwrap();
+
+        let exceptions = method("read").exceptions(pool).unwrap();
+        let names: Vec<&str> = exceptions.iter().map(|&index| class_file.class_name(index).unwrap()).collect();
+        assert_eq!(names, vec!["java/io/IOException"]);
+
+        assert_eq!(method("quiet").exceptions(pool).unwrap(), Vec::<u16>::new());
+    }
+
+    #[test]
+    fn test_inner_classes() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+        let pool = &class_file.constant_pool;
+
+        // The lambda's bootstrap method refers to MethodHandles.Lookup.
+        let inner_classes = class_file.inner_classes().unwrap();
+        assert_eq!(inner_classes.len(), 1);
+        let lookup = inner_classes[0];
+        assert_eq!(pool.resolve_utf8(lookup.inner_name_index), Some("Lookup"));
+        assert_eq!(lookup.inner_class_access_flags.bits(), 0x0019);
+        assert!(lookup.inner_class_access_flags.is_static());
+    }
+
+    #[test]
+    fn test_is_classfile() {
+        assert!(is_classfile(include_bytes!("../tests/fixtures/Hello.class")));
+        assert!(is_classfile(&MAGIC));
+        assert!(!is_classfile(&[0xCA, 0xFE]));
+        assert!(!is_classfile(b"PK\x03\x04"));
+    }
+
+    #[test]
+    fn test_verify_magic() {
+        let mut valid: &[u8] = &[0xCA, 0xFE, 0xBA, 0xBE, 0, 0];
+        verify_magic(&mut valid).unwrap();
+        // Only the magic is consumed.
+        assert_eq!(valid, &[0, 0]);
+
+        let mut short: &[u8] = &[0xCA, 0xFE];
+        assert!(matches!(verify_magic(&mut short).unwrap_err(), Error::UnexpectedEof { context: "magic" }));
+
+        let mut wrong: &[u8] = b"PK\x03\x04";
+        assert!(matches!(verify_magic(&mut wrong).unwrap_err(), Error::InvalidMagic(magic) if &magic == b"PK\x03\x04"));
+    }
+
+    #[test]
+    fn test_class_name() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(class_file.class_name(class_file.this_class), Some("Hello"));
+        assert_eq!(class_file.class_name(class_file.super_class), Some("java/lang/Object"));
+        assert_eq!(class_file.class_name(class_file.interfaces[0]), Some("java/lang/Runnable"));
+    }
+
+    #[test]
+    fn test_class_name_invalid_index() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(class_file.class_name(0), None);
+        assert_eq!(class_file.class_name(u16::MAX), None);
+        // #4 is the Utf8 "java/lang/Object", not the Class entry pointing at it.
+        assert_eq!(class_file.class_name(4), None);
+    }
+
+    #[test]
+    fn test_referenced_class_names() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(class_file.referenced_class_names(), vec![
+            "java/io/PrintStream",
+            "java/lang/Object",
+            "java/lang/Runnable",
+            "java/lang/String",
+            "java/lang/System",
+            "java/lang/invoke/LambdaMetafactory",
+            "java/lang/invoke/MethodHandles",
+            "java/lang/invoke/MethodHandles$Lookup",
+            "java/lang/invoke/StringConcatFactory",
+            "java/util/function/Supplier",
+        ]);
+    }
+
+    #[test]
+    fn test_referenced_array_class_names() {
+        let mut builder = ClassFileBuilder::new(ClassFileVersion(61, 0));
+        let name = builder.add_utf8("Main");
+        let this_class = builder.add_class(name);
+        for array in ["[[Ljava/lang/String;", "[Ljava/lang/String;", "[I"] {
+            let name = builder.add_utf8(array);
+            builder.add_class(name);
+        }
+        let class_file = builder.this_class(this_class).build().unwrap();
+
+        assert_eq!(class_file.referenced_class_names(), vec!["java/lang/String"]);
+    }
+
+    #[test]
+    fn test_treats_invokespecial_specially() {
+        let mut class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+        class_file.access_flags = ClassAccessFlags::PUBLIC;
+        assert!(class_file.treats_invokespecial_specially());
+
+        class_file.version = ClassFileVersion(51, 0);
+        assert!(!class_file.treats_invokespecial_specially());
+        class_file.access_flags = ClassAccessFlags::from_bits_truncate(0x0021);
+        assert!(class_file.treats_invokespecial_specially());
+    }
+
+    #[test]
+    fn test_constant_pool_slot_count() {
+        let bytes = class_bytes(&[
+            vec![6, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0],
+            utf8_entry("first"),
+            utf8_entry("second"),
+        ], EMPTY_BODY);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.constant_pool.len(), 3);
+        assert_eq!(class_file.constant_pool_slot_count(), 4);
+        assert_eq!(u16::from_be_bytes([bytes[8], bytes[9]]), 5);
+    }
+
+    #[test]
+    fn test_name_and_type() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(class_file.name_and_type(3), Some(("<init>", "()V")));
+        // #1 is the Methodref pointing at #3, not the NameAndType itself.
+        assert_eq!(class_file.name_and_type(1), None);
+        assert_eq!(class_file.name_and_type(0), None);
+    }
+
+    #[test]
+    fn test_resolve_method_ref() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        let println = class_file.resolve_method_ref(36).unwrap();
+        assert_eq!(println, MethodRefInfo {
+            class_name: "java/io/PrintStream",
+            method_name: "println",
+            descriptor: "(Ljava/lang/String;)V",
+        });
+        assert_eq!(println.to_string(), "java/io/PrintStream.println:(Ljava/lang/String;)V");
+
+        // #25 is an InterfaceMethodref, #9 a Fieldref.
+        assert_eq!(class_file.resolve_method_ref(25).unwrap().to_string(), "java/util/function/Supplier.get:()Ljava/lang/Object;");
+        assert_eq!(class_file.resolve_method_ref(9), None);
+    }
+
+    #[test]
+    fn test_this_and_super_class_name() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+
+        assert_eq!(class_file.this_class_name(), Some("Hello"));
+        assert_eq!(class_file.super_class_name(), Some("java/lang/Object"));
+    }
+
+    #[test]
+    fn test_object_has_no_super_class_name() {
+        // this_class = #2, super_class = 0, as in java/lang/Object itself.
+        let body = [0, 0x21, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let bytes = class_bytes(&[utf8_entry("java/lang/Object"), vec![7, 0, 1]], &body);
+        let class_file = read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(class_file.this_class_name(), Some("java/lang/Object"));
+        assert_eq!(class_file.super_class_name(), None);
+    }
+
+    #[test]
+    fn test_large_constant_pool() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Large.class")).unwrap();
+
+        assert_eq!(class_file.fields.len(), 2000);
+        let last = class_file.fields.last().unwrap();
+        let Some(ConstantPoolItem::String { string_index }) = last.constant_value(&class_file.constant_pool) else {
+            panic!("expected a String constant");
+        };
+        assert_eq!(class_file.constant_pool.resolve_utf8(*string_index), Some("constant number 1999"));
+    }
+
+    #[test]
+    fn test_read_from_partial() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        // Cut the file off partway through #4, the Utf8 "java/lang/Object".
+        let partial = read_from_partial(&bytes[..30]).unwrap_err();
+
+        assert_eq!(partial.version, Some(ClassFileVersion(61, 0)));
+        assert_eq!(partial.constant_pool_count, Some(90));
+        assert_eq!(partial.constant_pool.len(), 3);
+        assert_eq!(partial.constant_pool.get(2), Some(&ConstantPoolItem::Class { name_index: 4 }));
+        assert!(matches!(partial.error, Error::UnexpectedEof { context: "constant pool" }));
+
+        assert!(read_from_partial(bytes.as_slice()).is_ok());
+    }
+
+    #[test]
+    fn test_display_constant_pool_item() {
+        let cases = [
+            (ConstantPoolItem::Utf8("say \"hi\"".to_string()), r#"Utf8("say \"hi\"")"#),
+            (ConstantPoolItem::Integer(42), "Integer(42)"),
+            (ConstantPoolItem::Float(1.0), "Float(1.0)"),
+            (ConstantPoolItem::Long(-7), "Long(-7)"),
+            (ConstantPoolItem::Double(1.5), "Double(1.5)"),
+            (ConstantPoolItem::Class { name_index: 7 }, "Class(#7)"),
+            (ConstantPoolItem::String { string_index: 8 }, "String(#8)"),
+            (ConstantPoolItem::FieldRef { class_index: 3, name_and_type_index: 12 }, "FieldRef(#3.#12)"),
+            (ConstantPoolItem::MethodRef { class_index: 3, name_and_type_index: 12 }, "MethodRef(#3.#12)"),
+            (ConstantPoolItem::InterfaceMethodRef { class_index: 3, name_and_type_index: 12 }, "InterfaceMethodRef(#3.#12)"),
+            (ConstantPoolItem::NameAndType { name_index: 5, descriptor_index: 6 }, "NameAndType(#5:#6)"),
+            (
+                ConstantPoolItem::MethodHandle { reference_kind: ReferenceKind::InvokeStatic, reference_index: 65 },
+                "MethodHandle(InvokeStatic #65)",
+            ),
+            (ConstantPoolItem::MethodType { descriptor_index: 6 }, "MethodType(#6)"),
+            (ConstantPoolItem::Dynamic { bootstrap_method_attr_index: 0, name_and_type_index: 16 }, "Dynamic(0:#16)"),
+            (
+                ConstantPoolItem::InvokeDynamic { bootstrap_method_attr_index: 1, name_and_type_index: 33 },
+                "InvokeDynamic(1:#33)",
+            ),
+            (ConstantPoolItem::Module { name_index: 2 }, "Module(#2)"),
+            (ConstantPoolItem::Package { name_index: 2 }, "Package(#2)"),
+            (ConstantPoolItem::Unsupported { tag: 42, raw: vec![1, 2, 3] }, "Unsupported(tag 42, 3 bytes)"),
+        ];
+
+        for (item, expected) in cases {
+            assert_eq!(item.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_read_constant_pool_item_from_cursor() {
+        let mut cursor = std::io::Cursor::new(vec![7, 0, 4, 3, 0, 0, 0, 42]);
+        assert_eq!(read_constant_pool_item(&mut cursor).unwrap(), ConstantPoolItem::Class { name_index: 4 });
+        assert_eq!(read_constant_pool_item(&mut cursor).unwrap(), ConstantPoolItem::Integer(42));
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn test_read_all_from() {
+        let hello = include_bytes!("../tests/fixtures/Hello.class");
+        let throws = include_bytes!("../tests/fixtures/Throws.class");
+        let bytes = [hello.as_slice(), throws.as_slice()].concat();
+
+        let classes = read_all_from(bytes.as_slice()).unwrap();
+        assert_eq!(classes, vec![ClassFile::from_bytes(hello).unwrap(), ClassFile::from_bytes(throws).unwrap()]);
+
+        assert_eq!(read_all_from([].as_slice()).unwrap(), Vec::new());
+
+        let err = read_all_from(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { .. }), "{err:?}");
+    }
+
+    #[test]
+    fn test_hash_set_deduplicates() {
+        use std::collections::HashSet;
+
+        let items: HashSet<ConstantPoolItem> = [
+            ConstantPoolItem::Utf8("main".to_string()),
+            ConstantPoolItem::Utf8("main".to_string()),
+            ConstantPoolItem::Float(f32::NAN),
+            ConstantPoolItem::Float(f32::NAN),
+            ConstantPoolItem::Float(0.0),
+            ConstantPoolItem::Float(-0.0),
+            ConstantPoolItem::Integer(0),
+        ].into_iter().collect();
+
+        assert_eq!(items.len(), 5);
+        assert!(items.contains(&ConstantPoolItem::Float(f32::NAN)));
+        assert!(items.contains(&ConstantPoolItem::Float(-0.0)));
+    }
+
+    #[test]
+    fn test_try_from_bytes() -> Result<(), Error> {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class").as_slice();
+        let class_file: ClassFile = bytes.try_into()?;
+        assert_eq!(class_file.this_class_name(), Some("Hello"));
+
+        assert!(matches!(ClassFile::try_from([0u8; 4].as_slice()), Err(Error::InvalidMagic(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_eq() {
+        assert_eq!(read_from([0u8; 8].as_slice()).unwrap_err(), Error::InvalidMagic([0, 0, 0, 0]));
+        assert_eq!(read_from(MAGIC.as_slice()).unwrap_err(), Error::UnexpectedEof { context: "version" });
+        assert_ne!(Error::InvalidMagic([0, 0, 0, 0]), Error::InvalidMagic([0xCA, 0xFE, 0, 0]));
+
+        let not_found = Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(not_found, Error::from(std::io::Error::new(std::io::ErrorKind::NotFound, "missing")));
+        assert_ne!(not_found, Error::from(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)));
+    }
+
+    #[test]
+    fn test_parse_bytes_hostile_lengths() {
+        // An attribute claiming to be 4 GiB long, in a file that ends right after its header.
+        let mut body = EMPTY_BODY[..12].to_vec();
+        body.extend_from_slice(&[0, 1, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF]);
+        let bytes = class_bytes(&[utf8_entry("Code")], &body);
+        assert_eq!(parse_bytes(&bytes).unwrap_err(), Error::UnexpectedEof { context: "attributes" });
+
+        // A Code attribute whose code_length runs far past the end of the attribute.
+        let constant_pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("Code".to_string())]);
+        let attribute = AttributeInfo { name_index: 1, info: vec![0, 1, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0xB1] };
+        assert!(matches!(attribute.as_code(&constant_pool), Some(Err(Error::IoError(_)))));
+
+        // A constant_pool_count of 0, which a careless `count - 1` would underflow.
+        let mut bytes = class_bytes(&[], EMPTY_BODY);
+        bytes[8..10].copy_from_slice(&[0, 0]);
+        assert_eq!(parse_bytes(&bytes).unwrap_err(), Error::InvalidConstantPoolReference {
+            index: 0,
+            expected: ConstantPoolItemTag::Class,
+        });
+
+        for bytes in [&[][..], &MAGIC[..], &[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0xFF, 0xFF]] {
+            assert!(parse_bytes(bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_typed_attributes_hostile_counts() {
+        // Each attribute claims 65535 entries but holds none, so parsing it must fail without
+        // reserving room for all of them first.
+        let names = ["LineNumberTable", "LocalVariableTable", "InnerClasses", "BootstrapMethods", "Exceptions",
+            "RuntimeVisibleAnnotations", "StackMapTable"];
+        let constant_pool = ConstantPool::from(names.map(|name| ConstantPoolItem::Utf8(name.to_string())).to_vec());
+        let attribute = |name_index| AttributeInfo { name_index, info: vec![0xFF, 0xFF] };
+
+        assert!(matches!(attribute(1).as_line_number_table(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(2).as_local_variable_table(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(3).as_inner_classes(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(4).as_bootstrap_methods(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(5).as_exceptions(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(6).as_runtime_visible_annotations(&constant_pool), Some(Err(_))));
+        assert!(matches!(attribute(7).as_stack_map_table(&constant_pool), Some(Err(_))));
+
+        // @A(value = [[[...]]]), with every one of 64 nested arrays claiming 65535 values. Sized
+        // from the counts, that would be hundreds of megabytes reserved by a few hundred bytes.
+        let mut info = vec![0, 1, 0, 1, 0, 1, 0, 2];
+        for _ in 0..64 {
+            info.extend_from_slice(&[b'[', 0xFF, 0xFF]);
+        }
+        let annotations = AttributeInfo { name_index: 6, info };
+        let err = annotations.as_runtime_visible_annotations(&constant_pool).unwrap().unwrap_err();
+        assert!(matches!(err, Error::IoError(_)), "{err:?}");
+    }
+
+    #[test]
+    fn test_deprecated() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Legacy.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let deprecated = |name| {
+            let method = class_file.methods.iter().find(|method| pool.resolve_utf8(method.name_index) == Some(name));
+            method.unwrap().is_deprecated(pool)
+        };
+        assert!(deprecated("oldMethod"));
+        assert!(!deprecated("newMethod"));
+
+        let fields: Vec<bool> = class_file.fields.iter().map(|field| field.is_deprecated(pool)).collect();
+        assert_eq!(fields, vec![true, false]);
+    }
+
+    #[test]
+    fn test_synthetic() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let synthetic: Vec<_> = class_file.methods.iter()
+            .filter(|method| method.is_synthetic(pool))
+            .map(|method| pool.resolve_utf8(method.name_index).unwrap())
+            .collect();
+        assert_eq!(synthetic, vec!["lambda$run$0"]);
+
+        let pool = ConstantPool::from(vec![ConstantPoolItem::Utf8("Synthetic".to_string())]);
+        let field = FieldInfo {
+            access_flags: FieldAccessFlags::default(),
+            name_index: 0,
+            descriptor_index: 0,
+            attributes: vec![AttributeInfo { name_index: 1, info: Vec::new() }],
+        };
+        assert!(field.is_synthetic(&pool));
+        assert!(!field.is_deprecated(&pool));
+    }
+
+    #[test]
+    fn test_unknown_access_flags_round_trip() {
+        let mut body = EMPTY_BODY.to_vec();
+        body[..2].copy_from_slice(&[0x01, 0x21]);
+        let bytes = class_bytes(&[], &body);
+
+        let class_file = read_from(bytes.as_slice()).unwrap();
+        assert_eq!(class_file.access_flags.unknown_bits(), 0x0100);
+
+        let mut written = Vec::new();
+        write_to(&class_file, &mut written).unwrap();
+        assert_eq!(written, bytes);
+    }
+
+    #[test]
+    fn test_public_members() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Api.class")).unwrap();
+        let pool = &class_file.constant_pool;
+
+        let (fields, methods) = class_file.public_members();
+        let field_names: Vec<_> = fields.iter().map(|field| pool.resolve_utf8(field.name_index).unwrap()).collect();
+        assert_eq!(field_names, vec!["visible", "inherited"]);
+        let method_names: Vec<_> = methods.iter().map(|method| pool.resolve_utf8(method.name_index).unwrap()).collect();
+        assert_eq!(method_names, vec!["<init>", "open", "extend"]);
+    }
+
+    #[test]
+    fn test_inflated_interfaces_count() {
+        // Claims 65535 interfaces, followed by just one.
+        let body = [0, 0, 0, 1, 0, 0, 0xFF, 0xFF, 0, 1];
+        let bytes = class_bytes(&[utf8_entry("Main"), vec![7, 0, 1]], &body);
+
+        let expected = Error::UnexpectedEof { context: "interfaces" };
+        assert_eq!(ClassFile::from_bytes(&bytes).unwrap_err(), expected);
+        assert_eq!(read_from(bytes.as_slice()).unwrap_err(), expected);
+        assert_eq!(crate::ClassFileRef::from_bytes(&bytes).unwrap_err(), expected);
+        assert_eq!(crate::read_lazy(std::io::Cursor::new(&bytes)).unwrap_err(), expected);
+
+        struct Nothing;
+        impl crate::ClassVisitor for Nothing {}
+        assert_eq!(crate::read_with_visitor(bytes.as_slice(), &mut Nothing).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_inflated_member_counts() {
+        // No interfaces, then a claim of 65535 fields with none following.
+        let body = [0, 0, 0, 1, 0, 0, 0, 0, 0xFF, 0xFF];
+        let bytes = class_bytes(&[utf8_entry("Main"), vec![7, 0, 1]], &body);
+        assert_eq!(parse_bytes(&bytes).unwrap_err(), Error::UnexpectedEof { context: "fields" });
+
+        // A constant pool claiming 65535 entries, with only one there and nothing after it.
+        let mut bytes = class_bytes(&[utf8_entry("Main")], &[]);
+        bytes[8..10].copy_from_slice(&[0xFF, 0xFF]);
+        assert_eq!(parse_bytes(&bytes).unwrap_err(), Error::UnexpectedEof { context: "constant pool" });
+    }
+
+    #[test]
+    fn test_capacity_for() {
+        let buffered = [0u8; 7];
+
+        assert_eq!(capacity_for(buffered.as_slice(), u16::MAX, 2).unwrap(), 3);
+        assert_eq!(capacity_for(buffered.as_slice(), 2, 2).unwrap(), 2);
+        assert_eq!(capacity_for(buffered.as_slice(), u16::MAX, 8).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_resume_after_counted() {
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let hello = include_bytes!("../tests/fixtures/Hello.class");
+        let throws = include_bytes!("../tests/fixtures/Throws.class");
+        let mut cursor = Cursor::new([hello.as_slice(), throws.as_slice()].concat());
+
+        let (first, count) = read_from_counted(&mut cursor).unwrap();
+        assert_eq!(count, hello.len());
+        cursor.seek(SeekFrom::Start(count as u64)).unwrap();
+        let (second, _) = read_from_counted(&mut cursor).unwrap();
+
+        assert_eq!(first.this_class_name(), Some("Hello"));
+        assert_eq!(second.this_class_name(), Some("Throws"));
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let bytes = include_bytes!("../tests/fixtures/Hello.class");
+        let copy = std::fs::read("tests/fixtures/Hello.class").unwrap();
+        assert_eq!(ClassFile::content_hash(bytes), ClassFile::content_hash(&copy));
+
+        let mut flipped = bytes.to_vec();
+        flipped[100] ^= 1;
+        assert_ne!(ClassFile::content_hash(&flipped), ClassFile::content_hash(bytes));
+
+        // Reference values for FNV-1a.
+        assert_eq!(ClassFile::content_hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(ClassFile::content_hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_nest_host_and_members() {
+        let host = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Annotated.class")).unwrap();
+        assert!(host.nest_host().is_none());
+        let members: Vec<_> = host.nest_members().unwrap().into_iter().map(|index| host.class_name(index).unwrap()).collect();
+        assert_eq!(members, vec!["Annotated$Tags", "Annotated$Version"]);
+
+        let member = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Annotated$Tags.class")).unwrap();
+        let nest_host = member.nest_host().unwrap().unwrap();
+        assert_eq!(member.class_name(nest_host), Some("Annotated"));
+        assert_eq!(member.nest_members().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_permitted_subclasses() {
+        let sealed = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Shape.class")).unwrap();
+        assert_eq!(sealed.permitted_subclasses().unwrap().len(), 2);
+        assert_eq!(sealed.permitted_subclass_names().unwrap(), vec!["Shape$Circle", "Shape$Square"]);
+
+        let subclass = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Shape$Circle.class")).unwrap();
+        assert_eq!(subclass.permitted_subclass_names().unwrap(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_permitted_subclass_names_invalid_index() {
+        let mut class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Shape.class")).unwrap();
+        let name_index = class_file.attributes.iter()
+            .find(|attribute| attribute.name(&class_file.constant_pool) == Some("PermittedSubclasses"))
+            .unwrap()
+            .name_index;
+        // #2 is the Utf8 "Shape", not the Class entry pointing at it.
+        class_file.attributes = vec![AttributeInfo { name_index, info: vec![0, 1, 0, 2] }];
+
+        assert_eq!(class_file.permitted_subclass_names().unwrap_err(), Error::InvalidConstantPoolReference {
+            index: 2,
+            expected: ConstantPoolItemTag::Class,
+        });
     }
 
     #[test]
@@ -278,21 +2863,30 @@ mod test {
         // This isn't super-duper practical but it sure is neat!
         let addr: SocketAddr = "127.0.0.1:30245".parse().unwrap();
 
+        // Bind before spawning the client so it can't try to connect too early.
+        let socket = TcpListener::bind(addr).unwrap();
+
         let server = std::thread::spawn(move || {
-            let socket = TcpListener::bind(addr.clone()).unwrap();
             let (stream, _) = socket.accept().unwrap();
 
             let class_file = read_from(stream).unwrap();
 
             assert_eq!(class_file, ClassFile {
                 version: ClassFileVersion(10, 10),
-                constant_pool: Vec::new(),
+                constant_pool: ConstantPool::default(),
+                access_flags: ClassAccessFlags::default(),
+                this_class: 0,
+                super_class: 0,
+                interfaces: Vec::new(),
+                fields: Vec::new(),
+                methods: Vec::new(),
+                attributes: Vec::new(),
             });
         });
 
         let client = std::thread::spawn(move || {
-            let mut socket = TcpStream::connect(addr.clone()).unwrap();
-            socket.write_all(&[0xCA, 0xFE, 0xBA, 0xBE, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8]).unwrap();
+            let mut socket = TcpStream::connect(addr).unwrap();
+            socket.write_all(&class_bytes(&[], EMPTY_BODY)).unwrap();
         });
 
         client.join().unwrap();
@@ -300,4 +2894,4 @@ mod test {
         // Will rethrow any error thrown from the assert above
         server.join().unwrap();
     }
-}
\ No newline at end of file
+}
@@ -2,11 +2,13 @@
 /// * Field-less
 /// * Contain only a discriminant
 /// * Have a primitive representation
-/// * Implement the `TryFrom<$ty>` trait to allow for easy conversions from the primitive type
+/// * Implement the `TryFrom<$ty>` trait to allow for easy conversions from the primitive type,
+///   failing with the given `Error` variant when the value has no matching discriminant
 macro_rules! reversible_enum {
-    ($name:ident as $ty:ty, {
+    ($(#[$meta:meta])* $name:ident as $ty:ty => $err:path, {
         $($key:ident = $val:literal,)*
     }) => {
+        $(#[$meta])*
         #[repr($ty)]
         pub enum $name {
             $($key = $val),*
@@ -17,7 +19,7 @@ macro_rules! reversible_enum {
             fn try_from(value: $ty) -> Result<Self, Self::Error> {
                 match value {
                     $($val => Ok($name::$key),)*
-                    _ => Err(Self::Error::InvalidConstantPoolItemTag(value)),
+                    _ => Err($err(value)),
                 }
             }
         }
@@ -32,3 +34,82 @@ macro_rules! read_bytes {
         Ok(<$ty>::from_be_bytes(buf))
     }};
 }
+
+/// Helper macro to create a set of access flags. Access flag sets are
+/// * A newtype over the raw `u16` mask
+/// * Have an associated constant for each flag, which can be combined with `|`
+/// * Have an `is_*` predicate for each flag
+macro_rules! access_flags {
+    ($(#[$meta:meta])* $name:ident, {
+        $($flag:ident = $val:literal => $predicate:ident,)*
+    }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $name(u16);
+
+        impl $name {
+            $(pub const $flag: $name = $name($val);)*
+
+            const ALL: u16 = $($val)|*;
+
+            /// Decode a raw mask, dropping any bits that don't correspond to a known flag.
+            pub fn from_bits_truncate(bits: u16) -> Self {
+                $name(bits & Self::ALL)
+            }
+
+            /// Decode a raw mask, keeping any bits that don't correspond to a known flag so that
+            /// they're written back out unchanged. Classes are parsed this way.
+            pub fn from_bits_retain(bits: u16) -> Self {
+                $name(bits)
+            }
+
+            /// The bits that are set but don't correspond to any known flag. These are reserved by
+            /// the spec, so they're 0 in any well-formed class.
+            pub fn unknown_bits(&self) -> u16 {
+                self.0 & !Self::ALL
+            }
+
+            /// The raw mask, as it would be written to a class file.
+            pub fn bits(&self) -> u16 {
+                self.0
+            }
+
+            /// Returns true if every flag set in `other` is also set in `self`.
+            pub fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            $(
+                pub fn $predicate(&self) -> bool {
+                    self.contains($name::$flag)
+                }
+            )*
+
+            /// The names of the flags that are set, such as `"PUBLIC"`, in ascending bit order.
+            pub fn names(&self) -> impl Iterator<Item = &'static str> {
+                let flags = *self;
+                [$((stringify!($flag), $name::$flag)),*]
+                    .into_iter()
+                    .filter(move |(_, flag)| flags.contains(*flag))
+                    .map(|(name, _)| name)
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = $name;
+            fn bitor(self, rhs: Self) -> Self::Output {
+                $name(self.0 | rhs.0)
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut names: Vec<String> = self.names().map(str::to_string).collect();
+                if self.unknown_bits() != 0 {
+                    names.push(format!("{:#06x}", self.unknown_bits()));
+                }
+                write!(f, "{}({})", stringify!($name), names.join(" | "))
+            }
+        }
+    };
+}
@@ -0,0 +1,199 @@
+//! Support for the "modified UTF-8" encoding the JVM uses for `CONSTANT_Utf8_info` entries.
+//!
+//! Modified UTF-8 differs from standard UTF-8 in two ways:
+//! * The null character is encoded as the two-byte sequence `0xC0 0x80`, so the raw byte `0x00`
+//!   never appears.
+//! * Supplementary characters are stored as a UTF-16 surrogate pair, with each half encoded
+//!   separately as a three-byte sequence, for six bytes in total.
+
+use crate::Error;
+
+/// Decode a modified UTF-8 byte string into a Rust `String`.
+///
+/// Malformed input is reported as [`Error::ModifiedUtf8Error`] with the offset of the byte that
+/// begins the offending sequence.
+pub fn decode_modified_utf8(bytes: &[u8]) -> Result<String, Error> {
+    let mut decoded = String::with_capacity(bytes.len());
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (unit, len) = decode_unit(bytes, offset)?;
+
+        let c = match unit {
+            0xD800..=0xDBFF => {
+                // A high surrogate must be immediately followed by its low surrogate.
+                let low = match decode_unit(bytes, offset + len) {
+                    Ok((low @ 0xDC00..=0xDFFF, 3)) => low,
+                    _ => return Err(invalid(offset, "high surrogate is not followed by a low surrogate")),
+                };
+                let codepoint = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                offset += len + 3;
+                char::from_u32(codepoint)
+            }
+            0xDC00..=0xDFFF => return Err(invalid(offset, "low surrogate without a preceding high surrogate")),
+            _ => {
+                offset += len;
+                char::from_u32(unit)
+            }
+        };
+
+        // Every value outside the surrogate range is a valid char, and surrogate pairs always
+        // combine into one.
+        decoded.push(c.expect("decoded code unit is a valid char"));
+    }
+
+    Ok(decoded)
+}
+
+/// Decode the single one, two or three byte sequence starting at `offset`, returning the 16-bit
+/// code unit it encodes along with the number of bytes it occupied.
+fn decode_unit(bytes: &[u8], offset: usize) -> Result<(u32, usize), Error> {
+    let continuation = |index: usize| -> Result<u32, Error> {
+        match bytes.get(offset + index) {
+            Some(&byte) if byte & 0xC0 == 0x80 => Ok(byte as u32 & 0x3F),
+            Some(_) => Err(invalid(offset, "invalid continuation byte")),
+            None => Err(invalid(offset, "truncated multi-byte sequence")),
+        }
+    };
+
+    let Some(&lead) = bytes.get(offset) else {
+        return Err(invalid(offset, "truncated multi-byte sequence"));
+    };
+
+    match lead {
+        0x00 => Err(invalid(offset, "null byte must be encoded as 0xC0 0x80")),
+        0x01..=0x7F => Ok((lead as u32, 1)),
+        0xC0..=0xDF => {
+            let unit = (lead as u32 & 0x1F) << 6 | continuation(1)?;
+            // `0xC0 0x80` for the null character is the only overlong form modified UTF-8 allows.
+            match unit {
+                0x01..=0x7F => Err(invalid(offset, "overlong encoding")),
+                _ => Ok((unit, 2)),
+            }
+        }
+        0xE0..=0xEF => {
+            let unit = (lead as u32 & 0x0F) << 12 | continuation(1)? << 6 | continuation(2)?;
+            match unit {
+                0x00..=0x7FF => Err(invalid(offset, "overlong encoding")),
+                _ => Ok((unit, 3)),
+            }
+        }
+        _ => Err(invalid(offset, "invalid lead byte")),
+    }
+}
+
+/// Encode a string as modified UTF-8, the inverse of [`decode_modified_utf8`].
+pub fn encode_modified_utf8(value: &str) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(value.len());
+    let mut units = [0u16; 2];
+    for c in value.chars() {
+        // Anything outside the BMP is written as a surrogate pair, with each half encoded on its own.
+        for &unit in c.encode_utf16(&mut units).iter() {
+            let unit = unit as u32;
+            match unit {
+                0x01..=0x7F => encoded.push(unit as u8),
+                0x00 | 0x80..=0x7FF => {
+                    encoded.push(0xC0 | (unit >> 6) as u8);
+                    encoded.push(0x80 | (unit & 0x3F) as u8);
+                }
+                _ => {
+                    encoded.push(0xE0 | (unit >> 12) as u8);
+                    encoded.push(0x80 | ((unit >> 6) & 0x3F) as u8);
+                    encoded.push(0x80 | (unit & 0x3F) as u8);
+                }
+            }
+        }
+    }
+
+    encoded
+}
+
+fn invalid(offset: usize, reason: &'static str) -> Error {
+    Error::ModifiedUtf8Error { offset, reason }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Error;
+    use crate::mutf8::{decode_modified_utf8, encode_modified_utf8};
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(decode_modified_utf8(b"java/lang/Object").unwrap(), "java/lang/Object");
+    }
+
+    #[test]
+    fn test_multi_byte() {
+        assert_eq!(decode_modified_utf8("caf\u{e9} \u{20ac}".as_bytes()).unwrap(), "caf\u{e9} \u{20ac}");
+    }
+
+    #[test]
+    fn test_null() {
+        assert_eq!(decode_modified_utf8(&[b'a', 0xC0, 0x80, b'b']).unwrap(), "a\0b");
+    }
+
+    #[test]
+    fn test_supplementary_character() {
+        // U+1F600 is the surrogate pair D83D DE00.
+        let bytes = [0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80];
+        assert_eq!(decode_modified_utf8(&bytes).unwrap(), "\u{1F600}");
+    }
+
+    #[test]
+    fn test_invalid_lead_byte() {
+        let err = decode_modified_utf8(&[b'a', b'b', 0xFF]).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 2, reason: "invalid lead byte" }));
+    }
+
+    #[test]
+    fn test_overlong_two_byte() {
+        // 'A' written in two bytes.
+        let err = decode_modified_utf8(&[b'a', 0xC1, 0x81]).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 1, reason: "overlong encoding" }), "{err:?}");
+
+        let err = decode_modified_utf8(&[0xC0, 0x81]).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 0, reason: "overlong encoding" }), "{err:?}");
+
+        // The smallest value that really needs two bytes.
+        assert_eq!(decode_modified_utf8(&[0xC2, 0x80]).unwrap(), "\u{80}");
+    }
+
+    #[test]
+    fn test_overlong_three_byte() {
+        // U+0041 and U+07FF written in three bytes, and the null character, which may only be
+        // written as `0xC0 0x80`.
+        for bytes in [[0xE0, 0x81, 0x81], [0xE0, 0x9F, 0xBF], [0xE0, 0x80, 0x80]] {
+            let err = decode_modified_utf8(&bytes).unwrap_err();
+            assert!(matches!(err, Error::ModifiedUtf8Error { offset: 0, reason: "overlong encoding" }), "{err:?}");
+        }
+
+        // The smallest value that really needs three bytes.
+        assert_eq!(decode_modified_utf8(&[0xE0, 0xA0, 0x80]).unwrap(), "\u{800}");
+    }
+
+    #[test]
+    fn test_dangling_high_surrogate() {
+        let err = decode_modified_utf8(&[b'a', 0xED, 0xA0, 0xBD, b'b']).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 1, .. }));
+    }
+
+    #[test]
+    fn test_high_surrogate_at_end() {
+        let err = decode_modified_utf8(&[0xED, 0xA0, 0xBD]).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 0, .. }));
+    }
+
+    #[test]
+    fn test_truncated_sequence() {
+        let err = decode_modified_utf8(&[0xE2, 0x82]).unwrap_err();
+        assert!(matches!(err, Error::ModifiedUtf8Error { offset: 0, reason: "truncated multi-byte sequence" }));
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode_modified_utf8("a\0b"), vec![b'a', 0xC0, 0x80, b'b']);
+        assert_eq!(encode_modified_utf8("\u{1F600}"), vec![0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80]);
+
+        let value = "caf\u{e9} \u{20ac} \u{1F600}\0";
+        assert_eq!(decode_modified_utf8(&encode_modified_utf8(value)).unwrap(), value);
+    }
+}
@@ -0,0 +1,204 @@
+//! Comparison of classes by what their constant pool references resolve to, rather than by the
+//! indices themselves.
+
+use crate::{AttributeInfo, ClassFile, ConstantPool, ConstantPoolItem, ReadExt};
+
+/// How deep to follow references before giving up. Well-formed pools never nest more than a few
+/// levels, so this only guards against cycles in corrupt ones.
+const MAX_DEPTH: usize = 8;
+
+impl ClassFile {
+    /// Compare two classes by structure, resolving constant pool references on both sides instead
+    /// of comparing indices. Two compilers laying out the same pool in a different order produce
+    /// classes that are `semantically_eq` but not `==`.
+    ///
+    /// Entries that nothing references are ignored. Attributes whose layout this crate knows
+    /// about (`ConstantValue`, `SourceFile` and `BootstrapMethods`) are compared by their resolved
+    /// contents; any others, including `Code`, are compared as raw bytes, so they only match if
+    /// the indices inside them do.
+    pub fn semantically_eq(&self, other: &ClassFile) -> bool {
+        let pools = Pools { left: &self.constant_pool, right: &other.constant_pool };
+
+        self.version == other.version
+            && self.access_flags == other.access_flags
+            && pools.eq(self.this_class, other.this_class)
+            && pools.eq(self.super_class, other.super_class)
+            && pools.all_eq(&self.interfaces, &other.interfaces)
+            && self.fields.len() == other.fields.len()
+            && self.fields.iter().zip(&other.fields).all(|(left, right)| {
+                left.access_flags == right.access_flags
+                    && pools.eq(left.name_index, right.name_index)
+                    && pools.eq(left.descriptor_index, right.descriptor_index)
+                    && pools.attributes_eq(&left.attributes, &right.attributes)
+            })
+            && self.methods.len() == other.methods.len()
+            && self.methods.iter().zip(&other.methods).all(|(left, right)| {
+                left.access_flags == right.access_flags
+                    && pools.eq(left.name_index, right.name_index)
+                    && pools.eq(left.descriptor_index, right.descriptor_index)
+                    && pools.attributes_eq(&left.attributes, &right.attributes)
+            })
+            && pools.attributes_eq(&self.attributes, &other.attributes)
+    }
+}
+
+struct Pools<'a> {
+    left: &'a ConstantPool,
+    right: &'a ConstantPool,
+}
+
+impl Pools<'_> {
+    fn eq(&self, left: u16, right: u16) -> bool {
+        self.eq_at_depth(left, right, 0)
+    }
+
+    fn all_eq(&self, left: &[u16], right: &[u16]) -> bool {
+        left.len() == right.len() && left.iter().zip(right).all(|(&left, &right)| self.eq(left, right))
+    }
+
+    fn eq_at_depth(&self, left: u16, right: u16, depth: usize) -> bool {
+        use ConstantPoolItem::*;
+
+        if depth > MAX_DEPTH {
+            return false;
+        }
+        let eq = |left, right| self.eq_at_depth(left, right, depth + 1);
+
+        let (left_item, right_item) = match (self.left.get(left), self.right.get(right)) {
+            (Some(left_item), Some(right_item)) => (left_item, right_item),
+            // Index 0 stands for "none" in a few places, like super_class of java/lang/Object.
+            (None, None) => return left == 0 && right == 0,
+            _ => return false,
+        };
+
+        match (left_item, right_item) {
+            (Class { name_index: l }, Class { name_index: r })
+            | (String { string_index: l }, String { string_index: r })
+            | (MethodType { descriptor_index: l }, MethodType { descriptor_index: r })
+            | (Module { name_index: l }, Module { name_index: r })
+            | (Package { name_index: l }, Package { name_index: r }) => eq(*l, *r),
+            (FieldRef { class_index: lc, name_and_type_index: lnt }, FieldRef { class_index: rc, name_and_type_index: rnt })
+            | (MethodRef { class_index: lc, name_and_type_index: lnt }, MethodRef { class_index: rc, name_and_type_index: rnt })
+            | (
+                InterfaceMethodRef { class_index: lc, name_and_type_index: lnt },
+                InterfaceMethodRef { class_index: rc, name_and_type_index: rnt },
+            ) => eq(*lc, *rc) && eq(*lnt, *rnt),
+            (
+                NameAndType { name_index: ln, descriptor_index: ld },
+                NameAndType { name_index: rn, descriptor_index: rd },
+            ) => eq(*ln, *rn) && eq(*ld, *rd),
+            (
+                MethodHandle { reference_kind: lk, reference_index: li },
+                MethodHandle { reference_kind: rk, reference_index: ri },
+            ) => lk == rk && eq(*li, *ri),
+            // The bootstrap method index points into the BootstrapMethods attribute, which is
+            // compared on its own, so the indices have to match exactly.
+            (
+                Dynamic { bootstrap_method_attr_index: lb, name_and_type_index: lnt },
+                Dynamic { bootstrap_method_attr_index: rb, name_and_type_index: rnt },
+            )
+            | (
+                InvokeDynamic { bootstrap_method_attr_index: lb, name_and_type_index: lnt },
+                InvokeDynamic { bootstrap_method_attr_index: rb, name_and_type_index: rnt },
+            ) => lb == rb && eq(*lnt, *rnt),
+            (left_item, right_item) => left_item == right_item,
+        }
+    }
+
+    fn attributes_eq(&self, left: &[AttributeInfo], right: &[AttributeInfo]) -> bool {
+        left.len() == right.len() && left.iter().zip(right).all(|(left, right)| self.attribute_eq(left, right))
+    }
+
+    fn attribute_eq(&self, left: &AttributeInfo, right: &AttributeInfo) -> bool {
+        if !self.eq(left.name_index, right.name_index) {
+            return false;
+        }
+
+        if let (Some(left), Some(right)) = (left.as_bootstrap_methods(self.left), right.as_bootstrap_methods(self.right)) {
+            return match (left, right) {
+                (Ok(left), Ok(right)) => {
+                    left.len() == right.len() && left.iter().zip(&right).all(|(left, right)| {
+                        self.eq(left.method_ref, right.method_ref) && self.all_eq(&left.arguments, &right.arguments)
+                    })
+                }
+                _ => false,
+            };
+        }
+
+        match left.name(self.left) {
+            // Both of these hold a single constant pool index.
+            Some("ConstantValue" | "SourceFile") => {
+                match (left.info.as_slice().read_u16(), right.info.as_slice().read_u16()) {
+                    (Ok(l), Ok(r)) => left.info.len() == right.info.len() && self.eq(l, r),
+                    _ => false,
+                }
+            }
+            _ => left.info == right.info,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AttributeInfo, ClassAccessFlags, ClassFile, ClassFileVersion, ConstantPool, ConstantPoolItem};
+
+    fn class_with_pool(constant_pool: Vec<ConstantPoolItem>, this_class: u16, source_file: u16) -> ClassFile {
+        let source_file_name = constant_pool.iter()
+            .position(|item| *item == ConstantPoolItem::Utf8("SourceFile".to_string()))
+            .unwrap() as u16 + 1;
+
+        ClassFile {
+            version: ClassFileVersion(61, 0),
+            constant_pool: ConstantPool::from(constant_pool),
+            access_flags: ClassAccessFlags::PUBLIC,
+            this_class,
+            super_class: 0,
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            attributes: vec![AttributeInfo { name_index: source_file_name, info: source_file.to_be_bytes().to_vec() }],
+        }
+    }
+
+    #[test]
+    fn test_swapped_pool_entries() {
+        let left = class_with_pool(vec![
+            ConstantPoolItem::Utf8("Main".to_string()),
+            ConstantPoolItem::Class { name_index: 1 },
+            ConstantPoolItem::Utf8("SourceFile".to_string()),
+            ConstantPoolItem::Utf8("Main.java".to_string()),
+        ], 2, 4);
+        let right = class_with_pool(vec![
+            ConstantPoolItem::Utf8("Main.java".to_string()),
+            ConstantPoolItem::Utf8("SourceFile".to_string()),
+            ConstantPoolItem::Class { name_index: 4 },
+            ConstantPoolItem::Utf8("Main".to_string()),
+        ], 3, 1);
+
+        assert_ne!(left, right);
+        assert!(left.semantically_eq(&right));
+        assert!(right.semantically_eq(&left));
+    }
+
+    #[test]
+    fn test_different_entries() {
+        let left = class_with_pool(vec![
+            ConstantPoolItem::Utf8("Main".to_string()),
+            ConstantPoolItem::Class { name_index: 1 },
+            ConstantPoolItem::Utf8("SourceFile".to_string()),
+        ], 2, 1);
+        let right = class_with_pool(vec![
+            ConstantPoolItem::Utf8("Other".to_string()),
+            ConstantPoolItem::Class { name_index: 1 },
+            ConstantPoolItem::Utf8("SourceFile".to_string()),
+        ], 2, 1);
+
+        assert!(!left.semantically_eq(&right));
+    }
+
+    #[test]
+    fn test_fixture_is_semantically_eq_to_itself() {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Hello.class")).unwrap();
+        assert!(class_file.semantically_eq(&class_file.clone()));
+    }
+}
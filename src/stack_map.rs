@@ -0,0 +1,206 @@
+//! The `StackMapTable` attribute, which records the types of locals and stack entries at branch
+//! targets so that the verifier can check a method in a single pass.
+
+use std::io::BufRead;
+
+use crate::{capacity_for, Error, ReadExt};
+
+/// The type of a single local variable or operand stack entry in a [`StackMapFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationTypeInfo {
+    Top,
+    Integer,
+    Float,
+    /// A `double`. Unlike in the local variable array, this takes up a single entry here.
+    Double,
+    /// A `long`. Unlike in the local variable array, this takes up a single entry here.
+    Long,
+    Null,
+    /// `this` in a constructor, before the superclass constructor has been called.
+    UninitializedThis,
+    /// An instance of the class whose Class entry is at `cpool_index`.
+    Object { cpool_index: u16 },
+    /// An object created by the `new` instruction at bytecode offset `offset`, whose constructor
+    /// hasn't been called yet.
+    Uninitialized { offset: u16 },
+}
+
+/// One entry of a `StackMapTable` attribute.
+///
+/// Each frame applies at the bytecode offset reached by adding `offset_delta + 1` to the offset
+/// of the frame before it, or at `offset_delta` itself for the first frame. Every frame other than
+/// a `FullFrame` describes its locals relative to the previous frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackMapFrame {
+    /// The same locals as the previous frame and an empty stack.
+    SameFrame { offset_delta: u16 },
+    /// The same locals as the previous frame and a single stack entry.
+    SameLocals1StackItemFrame { offset_delta: u16, stack: VerificationTypeInfo },
+    /// `SameLocals1StackItemFrame` with an `offset_delta` too large for the compact encoding.
+    SameLocals1StackItemFrameExtended { offset_delta: u16, stack: VerificationTypeInfo },
+    /// The previous frame's locals minus the last `chopped` of them, from 1 to 3, and an empty
+    /// stack.
+    ChopFrame { offset_delta: u16, chopped: u8 },
+    /// `SameFrame` with an `offset_delta` too large for the compact encoding.
+    SameFrameExtended { offset_delta: u16 },
+    /// The previous frame's locals plus 1 to 3 more, and an empty stack.
+    AppendFrame { offset_delta: u16, locals: Vec<VerificationTypeInfo> },
+    /// All locals and stack entries given explicitly.
+    FullFrame { offset_delta: u16, locals: Vec<VerificationTypeInfo>, stack: Vec<VerificationTypeInfo> },
+}
+
+/// Read a `number_of_entries` count followed by that many stack map frames.
+pub(crate) fn read_stack_map_table<R>(mut buf_read: R) -> Result<Vec<StackMapFrame>, Error>
+    where R: BufRead,
+{
+    let number_of_entries = buf_read.read_u16()?;
+    // A same_frame is a single byte.
+    let mut frames = Vec::with_capacity(capacity_for(&mut buf_read, number_of_entries, 1)?);
+    for _ in 0..number_of_entries {
+        frames.push(read_frame(&mut buf_read)?);
+    }
+
+    Ok(frames)
+}
+
+fn read_frame<R>(mut buf_read: R) -> Result<StackMapFrame, Error>
+    where R: BufRead,
+{
+    let frame_type = buf_read.read_u8()?;
+    let frame = match frame_type {
+        0..=63 => StackMapFrame::SameFrame { offset_delta: frame_type as u16 },
+        64..=127 => StackMapFrame::SameLocals1StackItemFrame {
+            offset_delta: frame_type as u16 - 64,
+            stack: read_verification_type_info(&mut buf_read)?,
+        },
+        247 => StackMapFrame::SameLocals1StackItemFrameExtended {
+            offset_delta: buf_read.read_u16()?,
+            stack: read_verification_type_info(&mut buf_read)?,
+        },
+        248..=250 => StackMapFrame::ChopFrame {
+            offset_delta: buf_read.read_u16()?,
+            chopped: 251 - frame_type,
+        },
+        251 => StackMapFrame::SameFrameExtended { offset_delta: buf_read.read_u16()? },
+        252..=254 => {
+            let offset_delta = buf_read.read_u16()?;
+            let locals = read_verification_types(&mut buf_read, frame_type as u16 - 251)?;
+            StackMapFrame::AppendFrame { offset_delta, locals }
+        }
+        255 => {
+            let offset_delta = buf_read.read_u16()?;
+            let number_of_locals = buf_read.read_u16()?;
+            let locals = read_verification_types(&mut buf_read, number_of_locals)?;
+            let number_of_stack_items = buf_read.read_u16()?;
+            let stack = read_verification_types(&mut buf_read, number_of_stack_items)?;
+            StackMapFrame::FullFrame { offset_delta, locals, stack }
+        }
+        // 128 to 246 are reserved for future use.
+        _ => return Err(Error::InvalidStackMapFrameType(frame_type)),
+    };
+
+    Ok(frame)
+}
+
+fn read_verification_types<R>(mut buf_read: R, count: u16) -> Result<Vec<VerificationTypeInfo>, Error>
+    where R: BufRead,
+{
+    let mut types = Vec::with_capacity(capacity_for(&mut buf_read, count, 1)?);
+    for _ in 0..count {
+        types.push(read_verification_type_info(&mut buf_read)?);
+    }
+
+    Ok(types)
+}
+
+fn read_verification_type_info<R>(mut buf_read: R) -> Result<VerificationTypeInfo, Error>
+    where R: BufRead,
+{
+    let tag = buf_read.read_u8()?;
+    let info = match tag {
+        0 => VerificationTypeInfo::Top,
+        1 => VerificationTypeInfo::Integer,
+        2 => VerificationTypeInfo::Float,
+        3 => VerificationTypeInfo::Double,
+        4 => VerificationTypeInfo::Long,
+        5 => VerificationTypeInfo::Null,
+        6 => VerificationTypeInfo::UninitializedThis,
+        7 => VerificationTypeInfo::Object { cpool_index: buf_read.read_u16()? },
+        8 => VerificationTypeInfo::Uninitialized { offset: buf_read.read_u16()? },
+        _ => return Err(Error::InvalidVerificationTypeTag(tag)),
+    };
+
+    Ok(info)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ClassFile, ConstantPoolItem, Error, StackMapFrame, VerificationTypeInfo};
+    use crate::stack_map::read_stack_map_table;
+
+    fn frames(method_name: &str) -> (ClassFile, Vec<StackMapFrame>) {
+        let class_file = ClassFile::from_bytes(include_bytes!("../tests/fixtures/Frames.class")).unwrap();
+        let pool = &class_file.constant_pool;
+        let method = class_file.methods.iter()
+            .find(|method| pool.resolve_utf8(method.name_index) == Some(method_name))
+            .unwrap();
+
+        let code = method.code(pool).unwrap().unwrap();
+        let frames = code.stack_map_table(pool).unwrap();
+        (class_file, frames)
+    }
+
+    #[test]
+    fn test_same_frame() {
+        let (_, frames) = frames("sign");
+        assert_eq!(frames, vec![
+            StackMapFrame::SameFrame { offset_delta: 6 },
+            StackMapFrame::SameFrame { offset_delta: 7 },
+            StackMapFrame::SameLocals1StackItemFrame { offset_delta: 0, stack: VerificationTypeInfo::Integer },
+        ]);
+    }
+
+    #[test]
+    fn test_full_frame() {
+        let (class_file, frames) = frames("sum");
+        let StackMapFrame::FullFrame { offset_delta: 11, locals, stack } = &frames[0] else {
+            panic!("expected a full frame first, got {frames:?}");
+        };
+
+        let int_array = match locals[0] {
+            VerificationTypeInfo::Object { cpool_index } => cpool_index,
+            other => panic!("expected an object, got {other:?}"),
+        };
+        assert!(matches!(class_file.constant_pool.get(int_array), Some(ConstantPoolItem::Class { .. })));
+        assert_eq!(locals[1..], [
+            VerificationTypeInfo::Long,
+            VerificationTypeInfo::Object { cpool_index: int_array },
+            VerificationTypeInfo::Integer,
+            VerificationTypeInfo::Integer,
+        ]);
+        assert!(stack.is_empty());
+        assert_eq!(frames[1], StackMapFrame::ChopFrame { offset_delta: 24, chopped: 3 });
+    }
+
+    #[test]
+    fn test_append_frame() {
+        let (_, frames) = frames("describe");
+        assert!(matches!(frames[0], StackMapFrame::SameLocals1StackItemFrame { offset_delta: 8, .. }));
+        assert!(matches!(&frames[1], StackMapFrame::AppendFrame { offset_delta: 3, locals } if locals.len() == 1));
+    }
+
+    #[test]
+    fn test_extended_frames() {
+        let info: &[u8] = &[0, 2, 251, 0x01, 0x00, 247, 0x02, 0x00, 5];
+        assert_eq!(read_stack_map_table(info).unwrap(), vec![
+            StackMapFrame::SameFrameExtended { offset_delta: 256 },
+            StackMapFrame::SameLocals1StackItemFrameExtended { offset_delta: 512, stack: VerificationTypeInfo::Null },
+        ]);
+    }
+
+    #[test]
+    fn test_reserved_frame_type() {
+        let info: &[u8] = &[0, 1, 128];
+        assert!(matches!(read_stack_map_table(info).unwrap_err(), Error::InvalidStackMapFrameType(128)));
+    }
+}
@@ -0,0 +1,164 @@
+//! A streaming alternative to [`read_from`](crate::read_from) for tools that only need part of a
+//! class.
+//!
+//! [`read_with_visitor`] hands each piece of the class to a [`ClassVisitor`] as soon as it has
+//! been parsed and then drops it, so the full [`ClassFile`](crate::ClassFile) is never built.
+
+use std::io::{BufReader, Read};
+
+use crate::{
+    AttributeInfo, ClassAccessFlags, ClassFileVersion, ConstantPoolItem, CountingReader, EofContext, Error, FieldInfo,
+    MethodInfo, ParseOptions, read_attributes, read_constant_pool_entries, read_field_info, read_interfaces,
+    read_method_info, read_version, ReadExt,
+};
+
+/// Callbacks for each part of a class file, called in file order by [`read_with_visitor`]. Every
+/// method does nothing by default, so implementations only need the ones they care about.
+pub trait ClassVisitor {
+    fn visit_version(&mut self, _version: ClassFileVersion) {}
+
+    /// Called for each constant pool entry, along with its 1-based JVM index.
+    fn visit_constant(&mut self, _index: u16, _item: &ConstantPoolItem) {}
+
+    /// Called once the constant pool has been read, with the class's flags and the indices of the
+    /// Class entries for itself, its superclass and its interfaces.
+    fn visit_class(&mut self, _access_flags: ClassAccessFlags, _this_class: u16, _super_class: u16, _interfaces: &[u16]) {}
+
+    fn visit_field(&mut self, _field: &FieldInfo) {}
+
+    fn visit_method(&mut self, _method: &MethodInfo) {}
+
+    /// Called for each attribute of the class itself. Field and method attributes are passed along
+    /// with their [`FieldInfo`] and [`MethodInfo`].
+    fn visit_attribute(&mut self, _attribute: &AttributeInfo) {}
+}
+
+/// Parse a class file from `reader`, passing each part of it to `visitor`.
+pub fn read_with_visitor<R, V>(reader: R, visitor: &mut V) -> Result<(), Error>
+    where R: Read,
+          V: ClassVisitor,
+{
+    read_with_visitor_with_options(reader, visitor, ParseOptions::default())
+}
+
+/// Like [`read_with_visitor`], but with the same control over malformed input as
+/// [`read_from_with_options`](crate::read_from_with_options).
+pub fn read_with_visitor_with_options<R, V>(reader: R, visitor: &mut V, options: ParseOptions) -> Result<(), Error>
+    where R: Read,
+          V: ClassVisitor,
+{
+    let mut buf_read = CountingReader::new(BufReader::new(reader));
+
+    visitor.visit_version(read_version(&mut buf_read, &options)?);
+
+    let constant_pool_count = buf_read.read_u16().eof_context("constant pool")?;
+    read_constant_pool_entries(&mut buf_read, constant_pool_count, &options, |index, item| {
+        visitor.visit_constant(index, &item)
+    })?;
+
+    let access_flags = ClassAccessFlags::from_bits_retain(buf_read.read_u16().eof_context("access flags")?);
+    let this_class = buf_read.read_u16().eof_context("this_class")?;
+    let super_class = buf_read.read_u16().eof_context("super_class")?;
+    let interfaces = read_interfaces(&mut buf_read)?;
+    visitor.visit_class(access_flags, this_class, super_class, &interfaces);
+
+    let fields_count = buf_read.read_u16().eof_context("fields")?;
+    for _ in 0..fields_count {
+        visitor.visit_field(&read_field_info(&mut buf_read).eof_context("fields")?);
+    }
+
+    let methods_count = buf_read.read_u16().eof_context("methods")?;
+    for _ in 0..methods_count {
+        visitor.visit_method(&read_method_info(&mut buf_read).eof_context("methods")?);
+    }
+
+    for attribute in read_attributes(&mut buf_read).eof_context("attributes")? {
+        visitor.visit_attribute(&attribute);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ClassFile, ClassFileVersion, ClassVisitor, ConstantPoolItem, Error, MethodInfo, ParseOptions, read_with_visitor,
+        read_with_visitor_with_options,
+    };
+
+    const HELLO: &[u8] = include_bytes!("../tests/fixtures/Hello.class");
+
+    #[derive(Default)]
+    struct MethodNames {
+        version: Option<ClassFileVersion>,
+        name_indices: Vec<u16>,
+    }
+
+    impl ClassVisitor for MethodNames {
+        fn visit_version(&mut self, version: ClassFileVersion) {
+            self.version = Some(version);
+        }
+
+        fn visit_method(&mut self, method: &MethodInfo) {
+            self.name_indices.push(method.name_index);
+        }
+    }
+
+    #[test]
+    fn test_collect_method_names() {
+        let mut visitor = MethodNames::default();
+        read_with_visitor(HELLO, &mut visitor).unwrap();
+
+        let class_file = ClassFile::from_bytes(HELLO).unwrap();
+        let expected: Vec<u16> = class_file.methods.iter().map(|method| method.name_index).collect();
+        assert_eq!(visitor.name_indices, expected);
+        assert_eq!(visitor.version, Some(class_file.version));
+    }
+
+    #[test]
+    fn test_visit_constants_in_order() {
+        struct Indices(Vec<u16>);
+        impl ClassVisitor for Indices {
+            fn visit_constant(&mut self, index: u16, _item: &ConstantPoolItem) {
+                self.0.push(index);
+            }
+        }
+
+        let mut visitor = Indices(Vec::new());
+        read_with_visitor(HELLO, &mut visitor).unwrap();
+
+        let class_file = ClassFile::from_bytes(HELLO).unwrap();
+        let expected: Vec<u16> = class_file.constant_pool_iter().map(|(index, _)| index).collect();
+        assert_eq!(visitor.0, expected);
+    }
+
+    #[test]
+    fn test_invalid_tag_offset() {
+        struct Nothing;
+        impl ClassVisitor for Nothing {}
+
+        // The header is 10 bytes and the first entry, the MethodRef for Object's constructor, is
+        // another 5, so the tag of the second entry sits at byte 15.
+        let mut bytes = HELLO.to_vec();
+        bytes[15] = 2;
+
+        let err = read_with_visitor(bytes.as_slice(), &mut Nothing).unwrap_err();
+        assert_eq!(err, Error::InvalidConstantPoolItemTag { tag: 2, offset: 15 });
+    }
+
+    #[test]
+    fn test_max_major_version() {
+        struct Constants(usize);
+        impl ClassVisitor for Constants {
+            fn visit_constant(&mut self, _index: u16, _item: &ConstantPoolItem) {
+                self.0 += 1;
+            }
+        }
+
+        let mut visitor = Constants(0);
+        let options = ParseOptions { max_major_version: Some(52), ..ParseOptions::default() };
+        let err = read_with_visitor_with_options(HELLO, &mut visitor, options).unwrap_err();
+        assert!(matches!(err, Error::UnsupportedVersion { max: 52, .. }), "{err:?}");
+        assert_eq!(visitor.0, 0);
+    }
+}
@@ -0,0 +1,20 @@
+// Compiled with: javac -encoding UTF-8 Annotated.java
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+
+@Annotated.Version(3)
+public class Annotated {
+    @Retention(RetentionPolicy.RUNTIME)
+    @interface Version {
+        int value();
+    }
+
+    @Retention(RetentionPolicy.RUNTIME)
+    @interface Tags {
+        String[] value();
+    }
+
+    @Tags({"fast", "safe"})
+    void run() {
+    }
+}
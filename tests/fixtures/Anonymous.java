@@ -0,0 +1,12 @@
+// Compiled with: javac -encoding UTF-8 Anonymous.java
+public class Anonymous {
+    static final Runnable FIELD = new Runnable() {
+        public void run() {}
+    };
+
+    Runnable make() {
+        return new Runnable() {
+            public void run() {}
+        };
+    }
+}
@@ -0,0 +1,17 @@
+// Compiled with: javac -encoding UTF-8 Api.java
+public class Api {
+    public int visible;
+    protected int inherited;
+    int packaged;
+    private int hidden;
+
+    public Api() {}
+
+    public void open() {}
+
+    protected void extend() {}
+
+    void internal() {}
+
+    private void secret() {}
+}
@@ -0,0 +1,27 @@
+// Compiled with: javac -encoding UTF-8 Frames.java
+public class Frames {
+    static int sign(int x) {
+        if (x > 0) {
+            return 1;
+        }
+        return x < 0 ? -1 : 0;
+    }
+
+    static long sum(int[] values) {
+        long total = 0;
+        for (int value : values) {
+            total += value;
+        }
+        return total;
+    }
+
+    static String describe(Object value) {
+        String description;
+        try {
+            description = value.toString();
+        } catch (RuntimeException e) {
+            description = "?";
+        }
+        return description;
+    }
+}
@@ -0,0 +1,16 @@
+// Compiled with: javac -encoding UTF-8 Hello.java
+import java.util.function.Supplier;
+
+public class Hello implements Runnable {
+    static final long ANSWER = 42L;
+    private double ratio = 1.5;
+
+    public void run() {
+        Supplier<String> greeting = () -> "Hello, world! é€😀";
+        System.out.println(greeting.get() + ratio);
+    }
+
+    public static void main(String[] args) {
+        new Hello().run();
+    }
+}
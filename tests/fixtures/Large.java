@@ -0,0 +1,2004 @@
+// Compiled with: javac -encoding UTF-8 Large.java
+// Generated with: for i in $(seq 0 1999); do echo "    static final String S$i = \"constant number $i\";"; done
+public class Large {
+    static final String S0 = "constant number 0";
+    static final String S1 = "constant number 1";
+    static final String S2 = "constant number 2";
+    static final String S3 = "constant number 3";
+    static final String S4 = "constant number 4";
+    static final String S5 = "constant number 5";
+    static final String S6 = "constant number 6";
+    static final String S7 = "constant number 7";
+    static final String S8 = "constant number 8";
+    static final String S9 = "constant number 9";
+    static final String S10 = "constant number 10";
+    static final String S11 = "constant number 11";
+    static final String S12 = "constant number 12";
+    static final String S13 = "constant number 13";
+    static final String S14 = "constant number 14";
+    static final String S15 = "constant number 15";
+    static final String S16 = "constant number 16";
+    static final String S17 = "constant number 17";
+    static final String S18 = "constant number 18";
+    static final String S19 = "constant number 19";
+    static final String S20 = "constant number 20";
+    static final String S21 = "constant number 21";
+    static final String S22 = "constant number 22";
+    static final String S23 = "constant number 23";
+    static final String S24 = "constant number 24";
+    static final String S25 = "constant number 25";
+    static final String S26 = "constant number 26";
+    static final String S27 = "constant number 27";
+    static final String S28 = "constant number 28";
+    static final String S29 = "constant number 29";
+    static final String S30 = "constant number 30";
+    static final String S31 = "constant number 31";
+    static final String S32 = "constant number 32";
+    static final String S33 = "constant number 33";
+    static final String S34 = "constant number 34";
+    static final String S35 = "constant number 35";
+    static final String S36 = "constant number 36";
+    static final String S37 = "constant number 37";
+    static final String S38 = "constant number 38";
+    static final String S39 = "constant number 39";
+    static final String S40 = "constant number 40";
+    static final String S41 = "constant number 41";
+    static final String S42 = "constant number 42";
+    static final String S43 = "constant number 43";
+    static final String S44 = "constant number 44";
+    static final String S45 = "constant number 45";
+    static final String S46 = "constant number 46";
+    static final String S47 = "constant number 47";
+    static final String S48 = "constant number 48";
+    static final String S49 = "constant number 49";
+    static final String S50 = "constant number 50";
+    static final String S51 = "constant number 51";
+    static final String S52 = "constant number 52";
+    static final String S53 = "constant number 53";
+    static final String S54 = "constant number 54";
+    static final String S55 = "constant number 55";
+    static final String S56 = "constant number 56";
+    static final String S57 = "constant number 57";
+    static final String S58 = "constant number 58";
+    static final String S59 = "constant number 59";
+    static final String S60 = "constant number 60";
+    static final String S61 = "constant number 61";
+    static final String S62 = "constant number 62";
+    static final String S63 = "constant number 63";
+    static final String S64 = "constant number 64";
+    static final String S65 = "constant number 65";
+    static final String S66 = "constant number 66";
+    static final String S67 = "constant number 67";
+    static final String S68 = "constant number 68";
+    static final String S69 = "constant number 69";
+    static final String S70 = "constant number 70";
+    static final String S71 = "constant number 71";
+    static final String S72 = "constant number 72";
+    static final String S73 = "constant number 73";
+    static final String S74 = "constant number 74";
+    static final String S75 = "constant number 75";
+    static final String S76 = "constant number 76";
+    static final String S77 = "constant number 77";
+    static final String S78 = "constant number 78";
+    static final String S79 = "constant number 79";
+    static final String S80 = "constant number 80";
+    static final String S81 = "constant number 81";
+    static final String S82 = "constant number 82";
+    static final String S83 = "constant number 83";
+    static final String S84 = "constant number 84";
+    static final String S85 = "constant number 85";
+    static final String S86 = "constant number 86";
+    static final String S87 = "constant number 87";
+    static final String S88 = "constant number 88";
+    static final String S89 = "constant number 89";
+    static final String S90 = "constant number 90";
+    static final String S91 = "constant number 91";
+    static final String S92 = "constant number 92";
+    static final String S93 = "constant number 93";
+    static final String S94 = "constant number 94";
+    static final String S95 = "constant number 95";
+    static final String S96 = "constant number 96";
+    static final String S97 = "constant number 97";
+    static final String S98 = "constant number 98";
+    static final String S99 = "constant number 99";
+    static final String S100 = "constant number 100";
+    static final String S101 = "constant number 101";
+    static final String S102 = "constant number 102";
+    static final String S103 = "constant number 103";
+    static final String S104 = "constant number 104";
+    static final String S105 = "constant number 105";
+    static final String S106 = "constant number 106";
+    static final String S107 = "constant number 107";
+    static final String S108 = "constant number 108";
+    static final String S109 = "constant number 109";
+    static final String S110 = "constant number 110";
+    static final String S111 = "constant number 111";
+    static final String S112 = "constant number 112";
+    static final String S113 = "constant number 113";
+    static final String S114 = "constant number 114";
+    static final String S115 = "constant number 115";
+    static final String S116 = "constant number 116";
+    static final String S117 = "constant number 117";
+    static final String S118 = "constant number 118";
+    static final String S119 = "constant number 119";
+    static final String S120 = "constant number 120";
+    static final String S121 = "constant number 121";
+    static final String S122 = "constant number 122";
+    static final String S123 = "constant number 123";
+    static final String S124 = "constant number 124";
+    static final String S125 = "constant number 125";
+    static final String S126 = "constant number 126";
+    static final String S127 = "constant number 127";
+    static final String S128 = "constant number 128";
+    static final String S129 = "constant number 129";
+    static final String S130 = "constant number 130";
+    static final String S131 = "constant number 131";
+    static final String S132 = "constant number 132";
+    static final String S133 = "constant number 133";
+    static final String S134 = "constant number 134";
+    static final String S135 = "constant number 135";
+    static final String S136 = "constant number 136";
+    static final String S137 = "constant number 137";
+    static final String S138 = "constant number 138";
+    static final String S139 = "constant number 139";
+    static final String S140 = "constant number 140";
+    static final String S141 = "constant number 141";
+    static final String S142 = "constant number 142";
+    static final String S143 = "constant number 143";
+    static final String S144 = "constant number 144";
+    static final String S145 = "constant number 145";
+    static final String S146 = "constant number 146";
+    static final String S147 = "constant number 147";
+    static final String S148 = "constant number 148";
+    static final String S149 = "constant number 149";
+    static final String S150 = "constant number 150";
+    static final String S151 = "constant number 151";
+    static final String S152 = "constant number 152";
+    static final String S153 = "constant number 153";
+    static final String S154 = "constant number 154";
+    static final String S155 = "constant number 155";
+    static final String S156 = "constant number 156";
+    static final String S157 = "constant number 157";
+    static final String S158 = "constant number 158";
+    static final String S159 = "constant number 159";
+    static final String S160 = "constant number 160";
+    static final String S161 = "constant number 161";
+    static final String S162 = "constant number 162";
+    static final String S163 = "constant number 163";
+    static final String S164 = "constant number 164";
+    static final String S165 = "constant number 165";
+    static final String S166 = "constant number 166";
+    static final String S167 = "constant number 167";
+    static final String S168 = "constant number 168";
+    static final String S169 = "constant number 169";
+    static final String S170 = "constant number 170";
+    static final String S171 = "constant number 171";
+    static final String S172 = "constant number 172";
+    static final String S173 = "constant number 173";
+    static final String S174 = "constant number 174";
+    static final String S175 = "constant number 175";
+    static final String S176 = "constant number 176";
+    static final String S177 = "constant number 177";
+    static final String S178 = "constant number 178";
+    static final String S179 = "constant number 179";
+    static final String S180 = "constant number 180";
+    static final String S181 = "constant number 181";
+    static final String S182 = "constant number 182";
+    static final String S183 = "constant number 183";
+    static final String S184 = "constant number 184";
+    static final String S185 = "constant number 185";
+    static final String S186 = "constant number 186";
+    static final String S187 = "constant number 187";
+    static final String S188 = "constant number 188";
+    static final String S189 = "constant number 189";
+    static final String S190 = "constant number 190";
+    static final String S191 = "constant number 191";
+    static final String S192 = "constant number 192";
+    static final String S193 = "constant number 193";
+    static final String S194 = "constant number 194";
+    static final String S195 = "constant number 195";
+    static final String S196 = "constant number 196";
+    static final String S197 = "constant number 197";
+    static final String S198 = "constant number 198";
+    static final String S199 = "constant number 199";
+    static final String S200 = "constant number 200";
+    static final String S201 = "constant number 201";
+    static final String S202 = "constant number 202";
+    static final String S203 = "constant number 203";
+    static final String S204 = "constant number 204";
+    static final String S205 = "constant number 205";
+    static final String S206 = "constant number 206";
+    static final String S207 = "constant number 207";
+    static final String S208 = "constant number 208";
+    static final String S209 = "constant number 209";
+    static final String S210 = "constant number 210";
+    static final String S211 = "constant number 211";
+    static final String S212 = "constant number 212";
+    static final String S213 = "constant number 213";
+    static final String S214 = "constant number 214";
+    static final String S215 = "constant number 215";
+    static final String S216 = "constant number 216";
+    static final String S217 = "constant number 217";
+    static final String S218 = "constant number 218";
+    static final String S219 = "constant number 219";
+    static final String S220 = "constant number 220";
+    static final String S221 = "constant number 221";
+    static final String S222 = "constant number 222";
+    static final String S223 = "constant number 223";
+    static final String S224 = "constant number 224";
+    static final String S225 = "constant number 225";
+    static final String S226 = "constant number 226";
+    static final String S227 = "constant number 227";
+    static final String S228 = "constant number 228";
+    static final String S229 = "constant number 229";
+    static final String S230 = "constant number 230";
+    static final String S231 = "constant number 231";
+    static final String S232 = "constant number 232";
+    static final String S233 = "constant number 233";
+    static final String S234 = "constant number 234";
+    static final String S235 = "constant number 235";
+    static final String S236 = "constant number 236";
+    static final String S237 = "constant number 237";
+    static final String S238 = "constant number 238";
+    static final String S239 = "constant number 239";
+    static final String S240 = "constant number 240";
+    static final String S241 = "constant number 241";
+    static final String S242 = "constant number 242";
+    static final String S243 = "constant number 243";
+    static final String S244 = "constant number 244";
+    static final String S245 = "constant number 245";
+    static final String S246 = "constant number 246";
+    static final String S247 = "constant number 247";
+    static final String S248 = "constant number 248";
+    static final String S249 = "constant number 249";
+    static final String S250 = "constant number 250";
+    static final String S251 = "constant number 251";
+    static final String S252 = "constant number 252";
+    static final String S253 = "constant number 253";
+    static final String S254 = "constant number 254";
+    static final String S255 = "constant number 255";
+    static final String S256 = "constant number 256";
+    static final String S257 = "constant number 257";
+    static final String S258 = "constant number 258";
+    static final String S259 = "constant number 259";
+    static final String S260 = "constant number 260";
+    static final String S261 = "constant number 261";
+    static final String S262 = "constant number 262";
+    static final String S263 = "constant number 263";
+    static final String S264 = "constant number 264";
+    static final String S265 = "constant number 265";
+    static final String S266 = "constant number 266";
+    static final String S267 = "constant number 267";
+    static final String S268 = "constant number 268";
+    static final String S269 = "constant number 269";
+    static final String S270 = "constant number 270";
+    static final String S271 = "constant number 271";
+    static final String S272 = "constant number 272";
+    static final String S273 = "constant number 273";
+    static final String S274 = "constant number 274";
+    static final String S275 = "constant number 275";
+    static final String S276 = "constant number 276";
+    static final String S277 = "constant number 277";
+    static final String S278 = "constant number 278";
+    static final String S279 = "constant number 279";
+    static final String S280 = "constant number 280";
+    static final String S281 = "constant number 281";
+    static final String S282 = "constant number 282";
+    static final String S283 = "constant number 283";
+    static final String S284 = "constant number 284";
+    static final String S285 = "constant number 285";
+    static final String S286 = "constant number 286";
+    static final String S287 = "constant number 287";
+    static final String S288 = "constant number 288";
+    static final String S289 = "constant number 289";
+    static final String S290 = "constant number 290";
+    static final String S291 = "constant number 291";
+    static final String S292 = "constant number 292";
+    static final String S293 = "constant number 293";
+    static final String S294 = "constant number 294";
+    static final String S295 = "constant number 295";
+    static final String S296 = "constant number 296";
+    static final String S297 = "constant number 297";
+    static final String S298 = "constant number 298";
+    static final String S299 = "constant number 299";
+    static final String S300 = "constant number 300";
+    static final String S301 = "constant number 301";
+    static final String S302 = "constant number 302";
+    static final String S303 = "constant number 303";
+    static final String S304 = "constant number 304";
+    static final String S305 = "constant number 305";
+    static final String S306 = "constant number 306";
+    static final String S307 = "constant number 307";
+    static final String S308 = "constant number 308";
+    static final String S309 = "constant number 309";
+    static final String S310 = "constant number 310";
+    static final String S311 = "constant number 311";
+    static final String S312 = "constant number 312";
+    static final String S313 = "constant number 313";
+    static final String S314 = "constant number 314";
+    static final String S315 = "constant number 315";
+    static final String S316 = "constant number 316";
+    static final String S317 = "constant number 317";
+    static final String S318 = "constant number 318";
+    static final String S319 = "constant number 319";
+    static final String S320 = "constant number 320";
+    static final String S321 = "constant number 321";
+    static final String S322 = "constant number 322";
+    static final String S323 = "constant number 323";
+    static final String S324 = "constant number 324";
+    static final String S325 = "constant number 325";
+    static final String S326 = "constant number 326";
+    static final String S327 = "constant number 327";
+    static final String S328 = "constant number 328";
+    static final String S329 = "constant number 329";
+    static final String S330 = "constant number 330";
+    static final String S331 = "constant number 331";
+    static final String S332 = "constant number 332";
+    static final String S333 = "constant number 333";
+    static final String S334 = "constant number 334";
+    static final String S335 = "constant number 335";
+    static final String S336 = "constant number 336";
+    static final String S337 = "constant number 337";
+    static final String S338 = "constant number 338";
+    static final String S339 = "constant number 339";
+    static final String S340 = "constant number 340";
+    static final String S341 = "constant number 341";
+    static final String S342 = "constant number 342";
+    static final String S343 = "constant number 343";
+    static final String S344 = "constant number 344";
+    static final String S345 = "constant number 345";
+    static final String S346 = "constant number 346";
+    static final String S347 = "constant number 347";
+    static final String S348 = "constant number 348";
+    static final String S349 = "constant number 349";
+    static final String S350 = "constant number 350";
+    static final String S351 = "constant number 351";
+    static final String S352 = "constant number 352";
+    static final String S353 = "constant number 353";
+    static final String S354 = "constant number 354";
+    static final String S355 = "constant number 355";
+    static final String S356 = "constant number 356";
+    static final String S357 = "constant number 357";
+    static final String S358 = "constant number 358";
+    static final String S359 = "constant number 359";
+    static final String S360 = "constant number 360";
+    static final String S361 = "constant number 361";
+    static final String S362 = "constant number 362";
+    static final String S363 = "constant number 363";
+    static final String S364 = "constant number 364";
+    static final String S365 = "constant number 365";
+    static final String S366 = "constant number 366";
+    static final String S367 = "constant number 367";
+    static final String S368 = "constant number 368";
+    static final String S369 = "constant number 369";
+    static final String S370 = "constant number 370";
+    static final String S371 = "constant number 371";
+    static final String S372 = "constant number 372";
+    static final String S373 = "constant number 373";
+    static final String S374 = "constant number 374";
+    static final String S375 = "constant number 375";
+    static final String S376 = "constant number 376";
+    static final String S377 = "constant number 377";
+    static final String S378 = "constant number 378";
+    static final String S379 = "constant number 379";
+    static final String S380 = "constant number 380";
+    static final String S381 = "constant number 381";
+    static final String S382 = "constant number 382";
+    static final String S383 = "constant number 383";
+    static final String S384 = "constant number 384";
+    static final String S385 = "constant number 385";
+    static final String S386 = "constant number 386";
+    static final String S387 = "constant number 387";
+    static final String S388 = "constant number 388";
+    static final String S389 = "constant number 389";
+    static final String S390 = "constant number 390";
+    static final String S391 = "constant number 391";
+    static final String S392 = "constant number 392";
+    static final String S393 = "constant number 393";
+    static final String S394 = "constant number 394";
+    static final String S395 = "constant number 395";
+    static final String S396 = "constant number 396";
+    static final String S397 = "constant number 397";
+    static final String S398 = "constant number 398";
+    static final String S399 = "constant number 399";
+    static final String S400 = "constant number 400";
+    static final String S401 = "constant number 401";
+    static final String S402 = "constant number 402";
+    static final String S403 = "constant number 403";
+    static final String S404 = "constant number 404";
+    static final String S405 = "constant number 405";
+    static final String S406 = "constant number 406";
+    static final String S407 = "constant number 407";
+    static final String S408 = "constant number 408";
+    static final String S409 = "constant number 409";
+    static final String S410 = "constant number 410";
+    static final String S411 = "constant number 411";
+    static final String S412 = "constant number 412";
+    static final String S413 = "constant number 413";
+    static final String S414 = "constant number 414";
+    static final String S415 = "constant number 415";
+    static final String S416 = "constant number 416";
+    static final String S417 = "constant number 417";
+    static final String S418 = "constant number 418";
+    static final String S419 = "constant number 419";
+    static final String S420 = "constant number 420";
+    static final String S421 = "constant number 421";
+    static final String S422 = "constant number 422";
+    static final String S423 = "constant number 423";
+    static final String S424 = "constant number 424";
+    static final String S425 = "constant number 425";
+    static final String S426 = "constant number 426";
+    static final String S427 = "constant number 427";
+    static final String S428 = "constant number 428";
+    static final String S429 = "constant number 429";
+    static final String S430 = "constant number 430";
+    static final String S431 = "constant number 431";
+    static final String S432 = "constant number 432";
+    static final String S433 = "constant number 433";
+    static final String S434 = "constant number 434";
+    static final String S435 = "constant number 435";
+    static final String S436 = "constant number 436";
+    static final String S437 = "constant number 437";
+    static final String S438 = "constant number 438";
+    static final String S439 = "constant number 439";
+    static final String S440 = "constant number 440";
+    static final String S441 = "constant number 441";
+    static final String S442 = "constant number 442";
+    static final String S443 = "constant number 443";
+    static final String S444 = "constant number 444";
+    static final String S445 = "constant number 445";
+    static final String S446 = "constant number 446";
+    static final String S447 = "constant number 447";
+    static final String S448 = "constant number 448";
+    static final String S449 = "constant number 449";
+    static final String S450 = "constant number 450";
+    static final String S451 = "constant number 451";
+    static final String S452 = "constant number 452";
+    static final String S453 = "constant number 453";
+    static final String S454 = "constant number 454";
+    static final String S455 = "constant number 455";
+    static final String S456 = "constant number 456";
+    static final String S457 = "constant number 457";
+    static final String S458 = "constant number 458";
+    static final String S459 = "constant number 459";
+    static final String S460 = "constant number 460";
+    static final String S461 = "constant number 461";
+    static final String S462 = "constant number 462";
+    static final String S463 = "constant number 463";
+    static final String S464 = "constant number 464";
+    static final String S465 = "constant number 465";
+    static final String S466 = "constant number 466";
+    static final String S467 = "constant number 467";
+    static final String S468 = "constant number 468";
+    static final String S469 = "constant number 469";
+    static final String S470 = "constant number 470";
+    static final String S471 = "constant number 471";
+    static final String S472 = "constant number 472";
+    static final String S473 = "constant number 473";
+    static final String S474 = "constant number 474";
+    static final String S475 = "constant number 475";
+    static final String S476 = "constant number 476";
+    static final String S477 = "constant number 477";
+    static final String S478 = "constant number 478";
+    static final String S479 = "constant number 479";
+    static final String S480 = "constant number 480";
+    static final String S481 = "constant number 481";
+    static final String S482 = "constant number 482";
+    static final String S483 = "constant number 483";
+    static final String S484 = "constant number 484";
+    static final String S485 = "constant number 485";
+    static final String S486 = "constant number 486";
+    static final String S487 = "constant number 487";
+    static final String S488 = "constant number 488";
+    static final String S489 = "constant number 489";
+    static final String S490 = "constant number 490";
+    static final String S491 = "constant number 491";
+    static final String S492 = "constant number 492";
+    static final String S493 = "constant number 493";
+    static final String S494 = "constant number 494";
+    static final String S495 = "constant number 495";
+    static final String S496 = "constant number 496";
+    static final String S497 = "constant number 497";
+    static final String S498 = "constant number 498";
+    static final String S499 = "constant number 499";
+    static final String S500 = "constant number 500";
+    static final String S501 = "constant number 501";
+    static final String S502 = "constant number 502";
+    static final String S503 = "constant number 503";
+    static final String S504 = "constant number 504";
+    static final String S505 = "constant number 505";
+    static final String S506 = "constant number 506";
+    static final String S507 = "constant number 507";
+    static final String S508 = "constant number 508";
+    static final String S509 = "constant number 509";
+    static final String S510 = "constant number 510";
+    static final String S511 = "constant number 511";
+    static final String S512 = "constant number 512";
+    static final String S513 = "constant number 513";
+    static final String S514 = "constant number 514";
+    static final String S515 = "constant number 515";
+    static final String S516 = "constant number 516";
+    static final String S517 = "constant number 517";
+    static final String S518 = "constant number 518";
+    static final String S519 = "constant number 519";
+    static final String S520 = "constant number 520";
+    static final String S521 = "constant number 521";
+    static final String S522 = "constant number 522";
+    static final String S523 = "constant number 523";
+    static final String S524 = "constant number 524";
+    static final String S525 = "constant number 525";
+    static final String S526 = "constant number 526";
+    static final String S527 = "constant number 527";
+    static final String S528 = "constant number 528";
+    static final String S529 = "constant number 529";
+    static final String S530 = "constant number 530";
+    static final String S531 = "constant number 531";
+    static final String S532 = "constant number 532";
+    static final String S533 = "constant number 533";
+    static final String S534 = "constant number 534";
+    static final String S535 = "constant number 535";
+    static final String S536 = "constant number 536";
+    static final String S537 = "constant number 537";
+    static final String S538 = "constant number 538";
+    static final String S539 = "constant number 539";
+    static final String S540 = "constant number 540";
+    static final String S541 = "constant number 541";
+    static final String S542 = "constant number 542";
+    static final String S543 = "constant number 543";
+    static final String S544 = "constant number 544";
+    static final String S545 = "constant number 545";
+    static final String S546 = "constant number 546";
+    static final String S547 = "constant number 547";
+    static final String S548 = "constant number 548";
+    static final String S549 = "constant number 549";
+    static final String S550 = "constant number 550";
+    static final String S551 = "constant number 551";
+    static final String S552 = "constant number 552";
+    static final String S553 = "constant number 553";
+    static final String S554 = "constant number 554";
+    static final String S555 = "constant number 555";
+    static final String S556 = "constant number 556";
+    static final String S557 = "constant number 557";
+    static final String S558 = "constant number 558";
+    static final String S559 = "constant number 559";
+    static final String S560 = "constant number 560";
+    static final String S561 = "constant number 561";
+    static final String S562 = "constant number 562";
+    static final String S563 = "constant number 563";
+    static final String S564 = "constant number 564";
+    static final String S565 = "constant number 565";
+    static final String S566 = "constant number 566";
+    static final String S567 = "constant number 567";
+    static final String S568 = "constant number 568";
+    static final String S569 = "constant number 569";
+    static final String S570 = "constant number 570";
+    static final String S571 = "constant number 571";
+    static final String S572 = "constant number 572";
+    static final String S573 = "constant number 573";
+    static final String S574 = "constant number 574";
+    static final String S575 = "constant number 575";
+    static final String S576 = "constant number 576";
+    static final String S577 = "constant number 577";
+    static final String S578 = "constant number 578";
+    static final String S579 = "constant number 579";
+    static final String S580 = "constant number 580";
+    static final String S581 = "constant number 581";
+    static final String S582 = "constant number 582";
+    static final String S583 = "constant number 583";
+    static final String S584 = "constant number 584";
+    static final String S585 = "constant number 585";
+    static final String S586 = "constant number 586";
+    static final String S587 = "constant number 587";
+    static final String S588 = "constant number 588";
+    static final String S589 = "constant number 589";
+    static final String S590 = "constant number 590";
+    static final String S591 = "constant number 591";
+    static final String S592 = "constant number 592";
+    static final String S593 = "constant number 593";
+    static final String S594 = "constant number 594";
+    static final String S595 = "constant number 595";
+    static final String S596 = "constant number 596";
+    static final String S597 = "constant number 597";
+    static final String S598 = "constant number 598";
+    static final String S599 = "constant number 599";
+    static final String S600 = "constant number 600";
+    static final String S601 = "constant number 601";
+    static final String S602 = "constant number 602";
+    static final String S603 = "constant number 603";
+    static final String S604 = "constant number 604";
+    static final String S605 = "constant number 605";
+    static final String S606 = "constant number 606";
+    static final String S607 = "constant number 607";
+    static final String S608 = "constant number 608";
+    static final String S609 = "constant number 609";
+    static final String S610 = "constant number 610";
+    static final String S611 = "constant number 611";
+    static final String S612 = "constant number 612";
+    static final String S613 = "constant number 613";
+    static final String S614 = "constant number 614";
+    static final String S615 = "constant number 615";
+    static final String S616 = "constant number 616";
+    static final String S617 = "constant number 617";
+    static final String S618 = "constant number 618";
+    static final String S619 = "constant number 619";
+    static final String S620 = "constant number 620";
+    static final String S621 = "constant number 621";
+    static final String S622 = "constant number 622";
+    static final String S623 = "constant number 623";
+    static final String S624 = "constant number 624";
+    static final String S625 = "constant number 625";
+    static final String S626 = "constant number 626";
+    static final String S627 = "constant number 627";
+    static final String S628 = "constant number 628";
+    static final String S629 = "constant number 629";
+    static final String S630 = "constant number 630";
+    static final String S631 = "constant number 631";
+    static final String S632 = "constant number 632";
+    static final String S633 = "constant number 633";
+    static final String S634 = "constant number 634";
+    static final String S635 = "constant number 635";
+    static final String S636 = "constant number 636";
+    static final String S637 = "constant number 637";
+    static final String S638 = "constant number 638";
+    static final String S639 = "constant number 639";
+    static final String S640 = "constant number 640";
+    static final String S641 = "constant number 641";
+    static final String S642 = "constant number 642";
+    static final String S643 = "constant number 643";
+    static final String S644 = "constant number 644";
+    static final String S645 = "constant number 645";
+    static final String S646 = "constant number 646";
+    static final String S647 = "constant number 647";
+    static final String S648 = "constant number 648";
+    static final String S649 = "constant number 649";
+    static final String S650 = "constant number 650";
+    static final String S651 = "constant number 651";
+    static final String S652 = "constant number 652";
+    static final String S653 = "constant number 653";
+    static final String S654 = "constant number 654";
+    static final String S655 = "constant number 655";
+    static final String S656 = "constant number 656";
+    static final String S657 = "constant number 657";
+    static final String S658 = "constant number 658";
+    static final String S659 = "constant number 659";
+    static final String S660 = "constant number 660";
+    static final String S661 = "constant number 661";
+    static final String S662 = "constant number 662";
+    static final String S663 = "constant number 663";
+    static final String S664 = "constant number 664";
+    static final String S665 = "constant number 665";
+    static final String S666 = "constant number 666";
+    static final String S667 = "constant number 667";
+    static final String S668 = "constant number 668";
+    static final String S669 = "constant number 669";
+    static final String S670 = "constant number 670";
+    static final String S671 = "constant number 671";
+    static final String S672 = "constant number 672";
+    static final String S673 = "constant number 673";
+    static final String S674 = "constant number 674";
+    static final String S675 = "constant number 675";
+    static final String S676 = "constant number 676";
+    static final String S677 = "constant number 677";
+    static final String S678 = "constant number 678";
+    static final String S679 = "constant number 679";
+    static final String S680 = "constant number 680";
+    static final String S681 = "constant number 681";
+    static final String S682 = "constant number 682";
+    static final String S683 = "constant number 683";
+    static final String S684 = "constant number 684";
+    static final String S685 = "constant number 685";
+    static final String S686 = "constant number 686";
+    static final String S687 = "constant number 687";
+    static final String S688 = "constant number 688";
+    static final String S689 = "constant number 689";
+    static final String S690 = "constant number 690";
+    static final String S691 = "constant number 691";
+    static final String S692 = "constant number 692";
+    static final String S693 = "constant number 693";
+    static final String S694 = "constant number 694";
+    static final String S695 = "constant number 695";
+    static final String S696 = "constant number 696";
+    static final String S697 = "constant number 697";
+    static final String S698 = "constant number 698";
+    static final String S699 = "constant number 699";
+    static final String S700 = "constant number 700";
+    static final String S701 = "constant number 701";
+    static final String S702 = "constant number 702";
+    static final String S703 = "constant number 703";
+    static final String S704 = "constant number 704";
+    static final String S705 = "constant number 705";
+    static final String S706 = "constant number 706";
+    static final String S707 = "constant number 707";
+    static final String S708 = "constant number 708";
+    static final String S709 = "constant number 709";
+    static final String S710 = "constant number 710";
+    static final String S711 = "constant number 711";
+    static final String S712 = "constant number 712";
+    static final String S713 = "constant number 713";
+    static final String S714 = "constant number 714";
+    static final String S715 = "constant number 715";
+    static final String S716 = "constant number 716";
+    static final String S717 = "constant number 717";
+    static final String S718 = "constant number 718";
+    static final String S719 = "constant number 719";
+    static final String S720 = "constant number 720";
+    static final String S721 = "constant number 721";
+    static final String S722 = "constant number 722";
+    static final String S723 = "constant number 723";
+    static final String S724 = "constant number 724";
+    static final String S725 = "constant number 725";
+    static final String S726 = "constant number 726";
+    static final String S727 = "constant number 727";
+    static final String S728 = "constant number 728";
+    static final String S729 = "constant number 729";
+    static final String S730 = "constant number 730";
+    static final String S731 = "constant number 731";
+    static final String S732 = "constant number 732";
+    static final String S733 = "constant number 733";
+    static final String S734 = "constant number 734";
+    static final String S735 = "constant number 735";
+    static final String S736 = "constant number 736";
+    static final String S737 = "constant number 737";
+    static final String S738 = "constant number 738";
+    static final String S739 = "constant number 739";
+    static final String S740 = "constant number 740";
+    static final String S741 = "constant number 741";
+    static final String S742 = "constant number 742";
+    static final String S743 = "constant number 743";
+    static final String S744 = "constant number 744";
+    static final String S745 = "constant number 745";
+    static final String S746 = "constant number 746";
+    static final String S747 = "constant number 747";
+    static final String S748 = "constant number 748";
+    static final String S749 = "constant number 749";
+    static final String S750 = "constant number 750";
+    static final String S751 = "constant number 751";
+    static final String S752 = "constant number 752";
+    static final String S753 = "constant number 753";
+    static final String S754 = "constant number 754";
+    static final String S755 = "constant number 755";
+    static final String S756 = "constant number 756";
+    static final String S757 = "constant number 757";
+    static final String S758 = "constant number 758";
+    static final String S759 = "constant number 759";
+    static final String S760 = "constant number 760";
+    static final String S761 = "constant number 761";
+    static final String S762 = "constant number 762";
+    static final String S763 = "constant number 763";
+    static final String S764 = "constant number 764";
+    static final String S765 = "constant number 765";
+    static final String S766 = "constant number 766";
+    static final String S767 = "constant number 767";
+    static final String S768 = "constant number 768";
+    static final String S769 = "constant number 769";
+    static final String S770 = "constant number 770";
+    static final String S771 = "constant number 771";
+    static final String S772 = "constant number 772";
+    static final String S773 = "constant number 773";
+    static final String S774 = "constant number 774";
+    static final String S775 = "constant number 775";
+    static final String S776 = "constant number 776";
+    static final String S777 = "constant number 777";
+    static final String S778 = "constant number 778";
+    static final String S779 = "constant number 779";
+    static final String S780 = "constant number 780";
+    static final String S781 = "constant number 781";
+    static final String S782 = "constant number 782";
+    static final String S783 = "constant number 783";
+    static final String S784 = "constant number 784";
+    static final String S785 = "constant number 785";
+    static final String S786 = "constant number 786";
+    static final String S787 = "constant number 787";
+    static final String S788 = "constant number 788";
+    static final String S789 = "constant number 789";
+    static final String S790 = "constant number 790";
+    static final String S791 = "constant number 791";
+    static final String S792 = "constant number 792";
+    static final String S793 = "constant number 793";
+    static final String S794 = "constant number 794";
+    static final String S795 = "constant number 795";
+    static final String S796 = "constant number 796";
+    static final String S797 = "constant number 797";
+    static final String S798 = "constant number 798";
+    static final String S799 = "constant number 799";
+    static final String S800 = "constant number 800";
+    static final String S801 = "constant number 801";
+    static final String S802 = "constant number 802";
+    static final String S803 = "constant number 803";
+    static final String S804 = "constant number 804";
+    static final String S805 = "constant number 805";
+    static final String S806 = "constant number 806";
+    static final String S807 = "constant number 807";
+    static final String S808 = "constant number 808";
+    static final String S809 = "constant number 809";
+    static final String S810 = "constant number 810";
+    static final String S811 = "constant number 811";
+    static final String S812 = "constant number 812";
+    static final String S813 = "constant number 813";
+    static final String S814 = "constant number 814";
+    static final String S815 = "constant number 815";
+    static final String S816 = "constant number 816";
+    static final String S817 = "constant number 817";
+    static final String S818 = "constant number 818";
+    static final String S819 = "constant number 819";
+    static final String S820 = "constant number 820";
+    static final String S821 = "constant number 821";
+    static final String S822 = "constant number 822";
+    static final String S823 = "constant number 823";
+    static final String S824 = "constant number 824";
+    static final String S825 = "constant number 825";
+    static final String S826 = "constant number 826";
+    static final String S827 = "constant number 827";
+    static final String S828 = "constant number 828";
+    static final String S829 = "constant number 829";
+    static final String S830 = "constant number 830";
+    static final String S831 = "constant number 831";
+    static final String S832 = "constant number 832";
+    static final String S833 = "constant number 833";
+    static final String S834 = "constant number 834";
+    static final String S835 = "constant number 835";
+    static final String S836 = "constant number 836";
+    static final String S837 = "constant number 837";
+    static final String S838 = "constant number 838";
+    static final String S839 = "constant number 839";
+    static final String S840 = "constant number 840";
+    static final String S841 = "constant number 841";
+    static final String S842 = "constant number 842";
+    static final String S843 = "constant number 843";
+    static final String S844 = "constant number 844";
+    static final String S845 = "constant number 845";
+    static final String S846 = "constant number 846";
+    static final String S847 = "constant number 847";
+    static final String S848 = "constant number 848";
+    static final String S849 = "constant number 849";
+    static final String S850 = "constant number 850";
+    static final String S851 = "constant number 851";
+    static final String S852 = "constant number 852";
+    static final String S853 = "constant number 853";
+    static final String S854 = "constant number 854";
+    static final String S855 = "constant number 855";
+    static final String S856 = "constant number 856";
+    static final String S857 = "constant number 857";
+    static final String S858 = "constant number 858";
+    static final String S859 = "constant number 859";
+    static final String S860 = "constant number 860";
+    static final String S861 = "constant number 861";
+    static final String S862 = "constant number 862";
+    static final String S863 = "constant number 863";
+    static final String S864 = "constant number 864";
+    static final String S865 = "constant number 865";
+    static final String S866 = "constant number 866";
+    static final String S867 = "constant number 867";
+    static final String S868 = "constant number 868";
+    static final String S869 = "constant number 869";
+    static final String S870 = "constant number 870";
+    static final String S871 = "constant number 871";
+    static final String S872 = "constant number 872";
+    static final String S873 = "constant number 873";
+    static final String S874 = "constant number 874";
+    static final String S875 = "constant number 875";
+    static final String S876 = "constant number 876";
+    static final String S877 = "constant number 877";
+    static final String S878 = "constant number 878";
+    static final String S879 = "constant number 879";
+    static final String S880 = "constant number 880";
+    static final String S881 = "constant number 881";
+    static final String S882 = "constant number 882";
+    static final String S883 = "constant number 883";
+    static final String S884 = "constant number 884";
+    static final String S885 = "constant number 885";
+    static final String S886 = "constant number 886";
+    static final String S887 = "constant number 887";
+    static final String S888 = "constant number 888";
+    static final String S889 = "constant number 889";
+    static final String S890 = "constant number 890";
+    static final String S891 = "constant number 891";
+    static final String S892 = "constant number 892";
+    static final String S893 = "constant number 893";
+    static final String S894 = "constant number 894";
+    static final String S895 = "constant number 895";
+    static final String S896 = "constant number 896";
+    static final String S897 = "constant number 897";
+    static final String S898 = "constant number 898";
+    static final String S899 = "constant number 899";
+    static final String S900 = "constant number 900";
+    static final String S901 = "constant number 901";
+    static final String S902 = "constant number 902";
+    static final String S903 = "constant number 903";
+    static final String S904 = "constant number 904";
+    static final String S905 = "constant number 905";
+    static final String S906 = "constant number 906";
+    static final String S907 = "constant number 907";
+    static final String S908 = "constant number 908";
+    static final String S909 = "constant number 909";
+    static final String S910 = "constant number 910";
+    static final String S911 = "constant number 911";
+    static final String S912 = "constant number 912";
+    static final String S913 = "constant number 913";
+    static final String S914 = "constant number 914";
+    static final String S915 = "constant number 915";
+    static final String S916 = "constant number 916";
+    static final String S917 = "constant number 917";
+    static final String S918 = "constant number 918";
+    static final String S919 = "constant number 919";
+    static final String S920 = "constant number 920";
+    static final String S921 = "constant number 921";
+    static final String S922 = "constant number 922";
+    static final String S923 = "constant number 923";
+    static final String S924 = "constant number 924";
+    static final String S925 = "constant number 925";
+    static final String S926 = "constant number 926";
+    static final String S927 = "constant number 927";
+    static final String S928 = "constant number 928";
+    static final String S929 = "constant number 929";
+    static final String S930 = "constant number 930";
+    static final String S931 = "constant number 931";
+    static final String S932 = "constant number 932";
+    static final String S933 = "constant number 933";
+    static final String S934 = "constant number 934";
+    static final String S935 = "constant number 935";
+    static final String S936 = "constant number 936";
+    static final String S937 = "constant number 937";
+    static final String S938 = "constant number 938";
+    static final String S939 = "constant number 939";
+    static final String S940 = "constant number 940";
+    static final String S941 = "constant number 941";
+    static final String S942 = "constant number 942";
+    static final String S943 = "constant number 943";
+    static final String S944 = "constant number 944";
+    static final String S945 = "constant number 945";
+    static final String S946 = "constant number 946";
+    static final String S947 = "constant number 947";
+    static final String S948 = "constant number 948";
+    static final String S949 = "constant number 949";
+    static final String S950 = "constant number 950";
+    static final String S951 = "constant number 951";
+    static final String S952 = "constant number 952";
+    static final String S953 = "constant number 953";
+    static final String S954 = "constant number 954";
+    static final String S955 = "constant number 955";
+    static final String S956 = "constant number 956";
+    static final String S957 = "constant number 957";
+    static final String S958 = "constant number 958";
+    static final String S959 = "constant number 959";
+    static final String S960 = "constant number 960";
+    static final String S961 = "constant number 961";
+    static final String S962 = "constant number 962";
+    static final String S963 = "constant number 963";
+    static final String S964 = "constant number 964";
+    static final String S965 = "constant number 965";
+    static final String S966 = "constant number 966";
+    static final String S967 = "constant number 967";
+    static final String S968 = "constant number 968";
+    static final String S969 = "constant number 969";
+    static final String S970 = "constant number 970";
+    static final String S971 = "constant number 971";
+    static final String S972 = "constant number 972";
+    static final String S973 = "constant number 973";
+    static final String S974 = "constant number 974";
+    static final String S975 = "constant number 975";
+    static final String S976 = "constant number 976";
+    static final String S977 = "constant number 977";
+    static final String S978 = "constant number 978";
+    static final String S979 = "constant number 979";
+    static final String S980 = "constant number 980";
+    static final String S981 = "constant number 981";
+    static final String S982 = "constant number 982";
+    static final String S983 = "constant number 983";
+    static final String S984 = "constant number 984";
+    static final String S985 = "constant number 985";
+    static final String S986 = "constant number 986";
+    static final String S987 = "constant number 987";
+    static final String S988 = "constant number 988";
+    static final String S989 = "constant number 989";
+    static final String S990 = "constant number 990";
+    static final String S991 = "constant number 991";
+    static final String S992 = "constant number 992";
+    static final String S993 = "constant number 993";
+    static final String S994 = "constant number 994";
+    static final String S995 = "constant number 995";
+    static final String S996 = "constant number 996";
+    static final String S997 = "constant number 997";
+    static final String S998 = "constant number 998";
+    static final String S999 = "constant number 999";
+    static final String S1000 = "constant number 1000";
+    static final String S1001 = "constant number 1001";
+    static final String S1002 = "constant number 1002";
+    static final String S1003 = "constant number 1003";
+    static final String S1004 = "constant number 1004";
+    static final String S1005 = "constant number 1005";
+    static final String S1006 = "constant number 1006";
+    static final String S1007 = "constant number 1007";
+    static final String S1008 = "constant number 1008";
+    static final String S1009 = "constant number 1009";
+    static final String S1010 = "constant number 1010";
+    static final String S1011 = "constant number 1011";
+    static final String S1012 = "constant number 1012";
+    static final String S1013 = "constant number 1013";
+    static final String S1014 = "constant number 1014";
+    static final String S1015 = "constant number 1015";
+    static final String S1016 = "constant number 1016";
+    static final String S1017 = "constant number 1017";
+    static final String S1018 = "constant number 1018";
+    static final String S1019 = "constant number 1019";
+    static final String S1020 = "constant number 1020";
+    static final String S1021 = "constant number 1021";
+    static final String S1022 = "constant number 1022";
+    static final String S1023 = "constant number 1023";
+    static final String S1024 = "constant number 1024";
+    static final String S1025 = "constant number 1025";
+    static final String S1026 = "constant number 1026";
+    static final String S1027 = "constant number 1027";
+    static final String S1028 = "constant number 1028";
+    static final String S1029 = "constant number 1029";
+    static final String S1030 = "constant number 1030";
+    static final String S1031 = "constant number 1031";
+    static final String S1032 = "constant number 1032";
+    static final String S1033 = "constant number 1033";
+    static final String S1034 = "constant number 1034";
+    static final String S1035 = "constant number 1035";
+    static final String S1036 = "constant number 1036";
+    static final String S1037 = "constant number 1037";
+    static final String S1038 = "constant number 1038";
+    static final String S1039 = "constant number 1039";
+    static final String S1040 = "constant number 1040";
+    static final String S1041 = "constant number 1041";
+    static final String S1042 = "constant number 1042";
+    static final String S1043 = "constant number 1043";
+    static final String S1044 = "constant number 1044";
+    static final String S1045 = "constant number 1045";
+    static final String S1046 = "constant number 1046";
+    static final String S1047 = "constant number 1047";
+    static final String S1048 = "constant number 1048";
+    static final String S1049 = "constant number 1049";
+    static final String S1050 = "constant number 1050";
+    static final String S1051 = "constant number 1051";
+    static final String S1052 = "constant number 1052";
+    static final String S1053 = "constant number 1053";
+    static final String S1054 = "constant number 1054";
+    static final String S1055 = "constant number 1055";
+    static final String S1056 = "constant number 1056";
+    static final String S1057 = "constant number 1057";
+    static final String S1058 = "constant number 1058";
+    static final String S1059 = "constant number 1059";
+    static final String S1060 = "constant number 1060";
+    static final String S1061 = "constant number 1061";
+    static final String S1062 = "constant number 1062";
+    static final String S1063 = "constant number 1063";
+    static final String S1064 = "constant number 1064";
+    static final String S1065 = "constant number 1065";
+    static final String S1066 = "constant number 1066";
+    static final String S1067 = "constant number 1067";
+    static final String S1068 = "constant number 1068";
+    static final String S1069 = "constant number 1069";
+    static final String S1070 = "constant number 1070";
+    static final String S1071 = "constant number 1071";
+    static final String S1072 = "constant number 1072";
+    static final String S1073 = "constant number 1073";
+    static final String S1074 = "constant number 1074";
+    static final String S1075 = "constant number 1075";
+    static final String S1076 = "constant number 1076";
+    static final String S1077 = "constant number 1077";
+    static final String S1078 = "constant number 1078";
+    static final String S1079 = "constant number 1079";
+    static final String S1080 = "constant number 1080";
+    static final String S1081 = "constant number 1081";
+    static final String S1082 = "constant number 1082";
+    static final String S1083 = "constant number 1083";
+    static final String S1084 = "constant number 1084";
+    static final String S1085 = "constant number 1085";
+    static final String S1086 = "constant number 1086";
+    static final String S1087 = "constant number 1087";
+    static final String S1088 = "constant number 1088";
+    static final String S1089 = "constant number 1089";
+    static final String S1090 = "constant number 1090";
+    static final String S1091 = "constant number 1091";
+    static final String S1092 = "constant number 1092";
+    static final String S1093 = "constant number 1093";
+    static final String S1094 = "constant number 1094";
+    static final String S1095 = "constant number 1095";
+    static final String S1096 = "constant number 1096";
+    static final String S1097 = "constant number 1097";
+    static final String S1098 = "constant number 1098";
+    static final String S1099 = "constant number 1099";
+    static final String S1100 = "constant number 1100";
+    static final String S1101 = "constant number 1101";
+    static final String S1102 = "constant number 1102";
+    static final String S1103 = "constant number 1103";
+    static final String S1104 = "constant number 1104";
+    static final String S1105 = "constant number 1105";
+    static final String S1106 = "constant number 1106";
+    static final String S1107 = "constant number 1107";
+    static final String S1108 = "constant number 1108";
+    static final String S1109 = "constant number 1109";
+    static final String S1110 = "constant number 1110";
+    static final String S1111 = "constant number 1111";
+    static final String S1112 = "constant number 1112";
+    static final String S1113 = "constant number 1113";
+    static final String S1114 = "constant number 1114";
+    static final String S1115 = "constant number 1115";
+    static final String S1116 = "constant number 1116";
+    static final String S1117 = "constant number 1117";
+    static final String S1118 = "constant number 1118";
+    static final String S1119 = "constant number 1119";
+    static final String S1120 = "constant number 1120";
+    static final String S1121 = "constant number 1121";
+    static final String S1122 = "constant number 1122";
+    static final String S1123 = "constant number 1123";
+    static final String S1124 = "constant number 1124";
+    static final String S1125 = "constant number 1125";
+    static final String S1126 = "constant number 1126";
+    static final String S1127 = "constant number 1127";
+    static final String S1128 = "constant number 1128";
+    static final String S1129 = "constant number 1129";
+    static final String S1130 = "constant number 1130";
+    static final String S1131 = "constant number 1131";
+    static final String S1132 = "constant number 1132";
+    static final String S1133 = "constant number 1133";
+    static final String S1134 = "constant number 1134";
+    static final String S1135 = "constant number 1135";
+    static final String S1136 = "constant number 1136";
+    static final String S1137 = "constant number 1137";
+    static final String S1138 = "constant number 1138";
+    static final String S1139 = "constant number 1139";
+    static final String S1140 = "constant number 1140";
+    static final String S1141 = "constant number 1141";
+    static final String S1142 = "constant number 1142";
+    static final String S1143 = "constant number 1143";
+    static final String S1144 = "constant number 1144";
+    static final String S1145 = "constant number 1145";
+    static final String S1146 = "constant number 1146";
+    static final String S1147 = "constant number 1147";
+    static final String S1148 = "constant number 1148";
+    static final String S1149 = "constant number 1149";
+    static final String S1150 = "constant number 1150";
+    static final String S1151 = "constant number 1151";
+    static final String S1152 = "constant number 1152";
+    static final String S1153 = "constant number 1153";
+    static final String S1154 = "constant number 1154";
+    static final String S1155 = "constant number 1155";
+    static final String S1156 = "constant number 1156";
+    static final String S1157 = "constant number 1157";
+    static final String S1158 = "constant number 1158";
+    static final String S1159 = "constant number 1159";
+    static final String S1160 = "constant number 1160";
+    static final String S1161 = "constant number 1161";
+    static final String S1162 = "constant number 1162";
+    static final String S1163 = "constant number 1163";
+    static final String S1164 = "constant number 1164";
+    static final String S1165 = "constant number 1165";
+    static final String S1166 = "constant number 1166";
+    static final String S1167 = "constant number 1167";
+    static final String S1168 = "constant number 1168";
+    static final String S1169 = "constant number 1169";
+    static final String S1170 = "constant number 1170";
+    static final String S1171 = "constant number 1171";
+    static final String S1172 = "constant number 1172";
+    static final String S1173 = "constant number 1173";
+    static final String S1174 = "constant number 1174";
+    static final String S1175 = "constant number 1175";
+    static final String S1176 = "constant number 1176";
+    static final String S1177 = "constant number 1177";
+    static final String S1178 = "constant number 1178";
+    static final String S1179 = "constant number 1179";
+    static final String S1180 = "constant number 1180";
+    static final String S1181 = "constant number 1181";
+    static final String S1182 = "constant number 1182";
+    static final String S1183 = "constant number 1183";
+    static final String S1184 = "constant number 1184";
+    static final String S1185 = "constant number 1185";
+    static final String S1186 = "constant number 1186";
+    static final String S1187 = "constant number 1187";
+    static final String S1188 = "constant number 1188";
+    static final String S1189 = "constant number 1189";
+    static final String S1190 = "constant number 1190";
+    static final String S1191 = "constant number 1191";
+    static final String S1192 = "constant number 1192";
+    static final String S1193 = "constant number 1193";
+    static final String S1194 = "constant number 1194";
+    static final String S1195 = "constant number 1195";
+    static final String S1196 = "constant number 1196";
+    static final String S1197 = "constant number 1197";
+    static final String S1198 = "constant number 1198";
+    static final String S1199 = "constant number 1199";
+    static final String S1200 = "constant number 1200";
+    static final String S1201 = "constant number 1201";
+    static final String S1202 = "constant number 1202";
+    static final String S1203 = "constant number 1203";
+    static final String S1204 = "constant number 1204";
+    static final String S1205 = "constant number 1205";
+    static final String S1206 = "constant number 1206";
+    static final String S1207 = "constant number 1207";
+    static final String S1208 = "constant number 1208";
+    static final String S1209 = "constant number 1209";
+    static final String S1210 = "constant number 1210";
+    static final String S1211 = "constant number 1211";
+    static final String S1212 = "constant number 1212";
+    static final String S1213 = "constant number 1213";
+    static final String S1214 = "constant number 1214";
+    static final String S1215 = "constant number 1215";
+    static final String S1216 = "constant number 1216";
+    static final String S1217 = "constant number 1217";
+    static final String S1218 = "constant number 1218";
+    static final String S1219 = "constant number 1219";
+    static final String S1220 = "constant number 1220";
+    static final String S1221 = "constant number 1221";
+    static final String S1222 = "constant number 1222";
+    static final String S1223 = "constant number 1223";
+    static final String S1224 = "constant number 1224";
+    static final String S1225 = "constant number 1225";
+    static final String S1226 = "constant number 1226";
+    static final String S1227 = "constant number 1227";
+    static final String S1228 = "constant number 1228";
+    static final String S1229 = "constant number 1229";
+    static final String S1230 = "constant number 1230";
+    static final String S1231 = "constant number 1231";
+    static final String S1232 = "constant number 1232";
+    static final String S1233 = "constant number 1233";
+    static final String S1234 = "constant number 1234";
+    static final String S1235 = "constant number 1235";
+    static final String S1236 = "constant number 1236";
+    static final String S1237 = "constant number 1237";
+    static final String S1238 = "constant number 1238";
+    static final String S1239 = "constant number 1239";
+    static final String S1240 = "constant number 1240";
+    static final String S1241 = "constant number 1241";
+    static final String S1242 = "constant number 1242";
+    static final String S1243 = "constant number 1243";
+    static final String S1244 = "constant number 1244";
+    static final String S1245 = "constant number 1245";
+    static final String S1246 = "constant number 1246";
+    static final String S1247 = "constant number 1247";
+    static final String S1248 = "constant number 1248";
+    static final String S1249 = "constant number 1249";
+    static final String S1250 = "constant number 1250";
+    static final String S1251 = "constant number 1251";
+    static final String S1252 = "constant number 1252";
+    static final String S1253 = "constant number 1253";
+    static final String S1254 = "constant number 1254";
+    static final String S1255 = "constant number 1255";
+    static final String S1256 = "constant number 1256";
+    static final String S1257 = "constant number 1257";
+    static final String S1258 = "constant number 1258";
+    static final String S1259 = "constant number 1259";
+    static final String S1260 = "constant number 1260";
+    static final String S1261 = "constant number 1261";
+    static final String S1262 = "constant number 1262";
+    static final String S1263 = "constant number 1263";
+    static final String S1264 = "constant number 1264";
+    static final String S1265 = "constant number 1265";
+    static final String S1266 = "constant number 1266";
+    static final String S1267 = "constant number 1267";
+    static final String S1268 = "constant number 1268";
+    static final String S1269 = "constant number 1269";
+    static final String S1270 = "constant number 1270";
+    static final String S1271 = "constant number 1271";
+    static final String S1272 = "constant number 1272";
+    static final String S1273 = "constant number 1273";
+    static final String S1274 = "constant number 1274";
+    static final String S1275 = "constant number 1275";
+    static final String S1276 = "constant number 1276";
+    static final String S1277 = "constant number 1277";
+    static final String S1278 = "constant number 1278";
+    static final String S1279 = "constant number 1279";
+    static final String S1280 = "constant number 1280";
+    static final String S1281 = "constant number 1281";
+    static final String S1282 = "constant number 1282";
+    static final String S1283 = "constant number 1283";
+    static final String S1284 = "constant number 1284";
+    static final String S1285 = "constant number 1285";
+    static final String S1286 = "constant number 1286";
+    static final String S1287 = "constant number 1287";
+    static final String S1288 = "constant number 1288";
+    static final String S1289 = "constant number 1289";
+    static final String S1290 = "constant number 1290";
+    static final String S1291 = "constant number 1291";
+    static final String S1292 = "constant number 1292";
+    static final String S1293 = "constant number 1293";
+    static final String S1294 = "constant number 1294";
+    static final String S1295 = "constant number 1295";
+    static final String S1296 = "constant number 1296";
+    static final String S1297 = "constant number 1297";
+    static final String S1298 = "constant number 1298";
+    static final String S1299 = "constant number 1299";
+    static final String S1300 = "constant number 1300";
+    static final String S1301 = "constant number 1301";
+    static final String S1302 = "constant number 1302";
+    static final String S1303 = "constant number 1303";
+    static final String S1304 = "constant number 1304";
+    static final String S1305 = "constant number 1305";
+    static final String S1306 = "constant number 1306";
+    static final String S1307 = "constant number 1307";
+    static final String S1308 = "constant number 1308";
+    static final String S1309 = "constant number 1309";
+    static final String S1310 = "constant number 1310";
+    static final String S1311 = "constant number 1311";
+    static final String S1312 = "constant number 1312";
+    static final String S1313 = "constant number 1313";
+    static final String S1314 = "constant number 1314";
+    static final String S1315 = "constant number 1315";
+    static final String S1316 = "constant number 1316";
+    static final String S1317 = "constant number 1317";
+    static final String S1318 = "constant number 1318";
+    static final String S1319 = "constant number 1319";
+    static final String S1320 = "constant number 1320";
+    static final String S1321 = "constant number 1321";
+    static final String S1322 = "constant number 1322";
+    static final String S1323 = "constant number 1323";
+    static final String S1324 = "constant number 1324";
+    static final String S1325 = "constant number 1325";
+    static final String S1326 = "constant number 1326";
+    static final String S1327 = "constant number 1327";
+    static final String S1328 = "constant number 1328";
+    static final String S1329 = "constant number 1329";
+    static final String S1330 = "constant number 1330";
+    static final String S1331 = "constant number 1331";
+    static final String S1332 = "constant number 1332";
+    static final String S1333 = "constant number 1333";
+    static final String S1334 = "constant number 1334";
+    static final String S1335 = "constant number 1335";
+    static final String S1336 = "constant number 1336";
+    static final String S1337 = "constant number 1337";
+    static final String S1338 = "constant number 1338";
+    static final String S1339 = "constant number 1339";
+    static final String S1340 = "constant number 1340";
+    static final String S1341 = "constant number 1341";
+    static final String S1342 = "constant number 1342";
+    static final String S1343 = "constant number 1343";
+    static final String S1344 = "constant number 1344";
+    static final String S1345 = "constant number 1345";
+    static final String S1346 = "constant number 1346";
+    static final String S1347 = "constant number 1347";
+    static final String S1348 = "constant number 1348";
+    static final String S1349 = "constant number 1349";
+    static final String S1350 = "constant number 1350";
+    static final String S1351 = "constant number 1351";
+    static final String S1352 = "constant number 1352";
+    static final String S1353 = "constant number 1353";
+    static final String S1354 = "constant number 1354";
+    static final String S1355 = "constant number 1355";
+    static final String S1356 = "constant number 1356";
+    static final String S1357 = "constant number 1357";
+    static final String S1358 = "constant number 1358";
+    static final String S1359 = "constant number 1359";
+    static final String S1360 = "constant number 1360";
+    static final String S1361 = "constant number 1361";
+    static final String S1362 = "constant number 1362";
+    static final String S1363 = "constant number 1363";
+    static final String S1364 = "constant number 1364";
+    static final String S1365 = "constant number 1365";
+    static final String S1366 = "constant number 1366";
+    static final String S1367 = "constant number 1367";
+    static final String S1368 = "constant number 1368";
+    static final String S1369 = "constant number 1369";
+    static final String S1370 = "constant number 1370";
+    static final String S1371 = "constant number 1371";
+    static final String S1372 = "constant number 1372";
+    static final String S1373 = "constant number 1373";
+    static final String S1374 = "constant number 1374";
+    static final String S1375 = "constant number 1375";
+    static final String S1376 = "constant number 1376";
+    static final String S1377 = "constant number 1377";
+    static final String S1378 = "constant number 1378";
+    static final String S1379 = "constant number 1379";
+    static final String S1380 = "constant number 1380";
+    static final String S1381 = "constant number 1381";
+    static final String S1382 = "constant number 1382";
+    static final String S1383 = "constant number 1383";
+    static final String S1384 = "constant number 1384";
+    static final String S1385 = "constant number 1385";
+    static final String S1386 = "constant number 1386";
+    static final String S1387 = "constant number 1387";
+    static final String S1388 = "constant number 1388";
+    static final String S1389 = "constant number 1389";
+    static final String S1390 = "constant number 1390";
+    static final String S1391 = "constant number 1391";
+    static final String S1392 = "constant number 1392";
+    static final String S1393 = "constant number 1393";
+    static final String S1394 = "constant number 1394";
+    static final String S1395 = "constant number 1395";
+    static final String S1396 = "constant number 1396";
+    static final String S1397 = "constant number 1397";
+    static final String S1398 = "constant number 1398";
+    static final String S1399 = "constant number 1399";
+    static final String S1400 = "constant number 1400";
+    static final String S1401 = "constant number 1401";
+    static final String S1402 = "constant number 1402";
+    static final String S1403 = "constant number 1403";
+    static final String S1404 = "constant number 1404";
+    static final String S1405 = "constant number 1405";
+    static final String S1406 = "constant number 1406";
+    static final String S1407 = "constant number 1407";
+    static final String S1408 = "constant number 1408";
+    static final String S1409 = "constant number 1409";
+    static final String S1410 = "constant number 1410";
+    static final String S1411 = "constant number 1411";
+    static final String S1412 = "constant number 1412";
+    static final String S1413 = "constant number 1413";
+    static final String S1414 = "constant number 1414";
+    static final String S1415 = "constant number 1415";
+    static final String S1416 = "constant number 1416";
+    static final String S1417 = "constant number 1417";
+    static final String S1418 = "constant number 1418";
+    static final String S1419 = "constant number 1419";
+    static final String S1420 = "constant number 1420";
+    static final String S1421 = "constant number 1421";
+    static final String S1422 = "constant number 1422";
+    static final String S1423 = "constant number 1423";
+    static final String S1424 = "constant number 1424";
+    static final String S1425 = "constant number 1425";
+    static final String S1426 = "constant number 1426";
+    static final String S1427 = "constant number 1427";
+    static final String S1428 = "constant number 1428";
+    static final String S1429 = "constant number 1429";
+    static final String S1430 = "constant number 1430";
+    static final String S1431 = "constant number 1431";
+    static final String S1432 = "constant number 1432";
+    static final String S1433 = "constant number 1433";
+    static final String S1434 = "constant number 1434";
+    static final String S1435 = "constant number 1435";
+    static final String S1436 = "constant number 1436";
+    static final String S1437 = "constant number 1437";
+    static final String S1438 = "constant number 1438";
+    static final String S1439 = "constant number 1439";
+    static final String S1440 = "constant number 1440";
+    static final String S1441 = "constant number 1441";
+    static final String S1442 = "constant number 1442";
+    static final String S1443 = "constant number 1443";
+    static final String S1444 = "constant number 1444";
+    static final String S1445 = "constant number 1445";
+    static final String S1446 = "constant number 1446";
+    static final String S1447 = "constant number 1447";
+    static final String S1448 = "constant number 1448";
+    static final String S1449 = "constant number 1449";
+    static final String S1450 = "constant number 1450";
+    static final String S1451 = "constant number 1451";
+    static final String S1452 = "constant number 1452";
+    static final String S1453 = "constant number 1453";
+    static final String S1454 = "constant number 1454";
+    static final String S1455 = "constant number 1455";
+    static final String S1456 = "constant number 1456";
+    static final String S1457 = "constant number 1457";
+    static final String S1458 = "constant number 1458";
+    static final String S1459 = "constant number 1459";
+    static final String S1460 = "constant number 1460";
+    static final String S1461 = "constant number 1461";
+    static final String S1462 = "constant number 1462";
+    static final String S1463 = "constant number 1463";
+    static final String S1464 = "constant number 1464";
+    static final String S1465 = "constant number 1465";
+    static final String S1466 = "constant number 1466";
+    static final String S1467 = "constant number 1467";
+    static final String S1468 = "constant number 1468";
+    static final String S1469 = "constant number 1469";
+    static final String S1470 = "constant number 1470";
+    static final String S1471 = "constant number 1471";
+    static final String S1472 = "constant number 1472";
+    static final String S1473 = "constant number 1473";
+    static final String S1474 = "constant number 1474";
+    static final String S1475 = "constant number 1475";
+    static final String S1476 = "constant number 1476";
+    static final String S1477 = "constant number 1477";
+    static final String S1478 = "constant number 1478";
+    static final String S1479 = "constant number 1479";
+    static final String S1480 = "constant number 1480";
+    static final String S1481 = "constant number 1481";
+    static final String S1482 = "constant number 1482";
+    static final String S1483 = "constant number 1483";
+    static final String S1484 = "constant number 1484";
+    static final String S1485 = "constant number 1485";
+    static final String S1486 = "constant number 1486";
+    static final String S1487 = "constant number 1487";
+    static final String S1488 = "constant number 1488";
+    static final String S1489 = "constant number 1489";
+    static final String S1490 = "constant number 1490";
+    static final String S1491 = "constant number 1491";
+    static final String S1492 = "constant number 1492";
+    static final String S1493 = "constant number 1493";
+    static final String S1494 = "constant number 1494";
+    static final String S1495 = "constant number 1495";
+    static final String S1496 = "constant number 1496";
+    static final String S1497 = "constant number 1497";
+    static final String S1498 = "constant number 1498";
+    static final String S1499 = "constant number 1499";
+    static final String S1500 = "constant number 1500";
+    static final String S1501 = "constant number 1501";
+    static final String S1502 = "constant number 1502";
+    static final String S1503 = "constant number 1503";
+    static final String S1504 = "constant number 1504";
+    static final String S1505 = "constant number 1505";
+    static final String S1506 = "constant number 1506";
+    static final String S1507 = "constant number 1507";
+    static final String S1508 = "constant number 1508";
+    static final String S1509 = "constant number 1509";
+    static final String S1510 = "constant number 1510";
+    static final String S1511 = "constant number 1511";
+    static final String S1512 = "constant number 1512";
+    static final String S1513 = "constant number 1513";
+    static final String S1514 = "constant number 1514";
+    static final String S1515 = "constant number 1515";
+    static final String S1516 = "constant number 1516";
+    static final String S1517 = "constant number 1517";
+    static final String S1518 = "constant number 1518";
+    static final String S1519 = "constant number 1519";
+    static final String S1520 = "constant number 1520";
+    static final String S1521 = "constant number 1521";
+    static final String S1522 = "constant number 1522";
+    static final String S1523 = "constant number 1523";
+    static final String S1524 = "constant number 1524";
+    static final String S1525 = "constant number 1525";
+    static final String S1526 = "constant number 1526";
+    static final String S1527 = "constant number 1527";
+    static final String S1528 = "constant number 1528";
+    static final String S1529 = "constant number 1529";
+    static final String S1530 = "constant number 1530";
+    static final String S1531 = "constant number 1531";
+    static final String S1532 = "constant number 1532";
+    static final String S1533 = "constant number 1533";
+    static final String S1534 = "constant number 1534";
+    static final String S1535 = "constant number 1535";
+    static final String S1536 = "constant number 1536";
+    static final String S1537 = "constant number 1537";
+    static final String S1538 = "constant number 1538";
+    static final String S1539 = "constant number 1539";
+    static final String S1540 = "constant number 1540";
+    static final String S1541 = "constant number 1541";
+    static final String S1542 = "constant number 1542";
+    static final String S1543 = "constant number 1543";
+    static final String S1544 = "constant number 1544";
+    static final String S1545 = "constant number 1545";
+    static final String S1546 = "constant number 1546";
+    static final String S1547 = "constant number 1547";
+    static final String S1548 = "constant number 1548";
+    static final String S1549 = "constant number 1549";
+    static final String S1550 = "constant number 1550";
+    static final String S1551 = "constant number 1551";
+    static final String S1552 = "constant number 1552";
+    static final String S1553 = "constant number 1553";
+    static final String S1554 = "constant number 1554";
+    static final String S1555 = "constant number 1555";
+    static final String S1556 = "constant number 1556";
+    static final String S1557 = "constant number 1557";
+    static final String S1558 = "constant number 1558";
+    static final String S1559 = "constant number 1559";
+    static final String S1560 = "constant number 1560";
+    static final String S1561 = "constant number 1561";
+    static final String S1562 = "constant number 1562";
+    static final String S1563 = "constant number 1563";
+    static final String S1564 = "constant number 1564";
+    static final String S1565 = "constant number 1565";
+    static final String S1566 = "constant number 1566";
+    static final String S1567 = "constant number 1567";
+    static final String S1568 = "constant number 1568";
+    static final String S1569 = "constant number 1569";
+    static final String S1570 = "constant number 1570";
+    static final String S1571 = "constant number 1571";
+    static final String S1572 = "constant number 1572";
+    static final String S1573 = "constant number 1573";
+    static final String S1574 = "constant number 1574";
+    static final String S1575 = "constant number 1575";
+    static final String S1576 = "constant number 1576";
+    static final String S1577 = "constant number 1577";
+    static final String S1578 = "constant number 1578";
+    static final String S1579 = "constant number 1579";
+    static final String S1580 = "constant number 1580";
+    static final String S1581 = "constant number 1581";
+    static final String S1582 = "constant number 1582";
+    static final String S1583 = "constant number 1583";
+    static final String S1584 = "constant number 1584";
+    static final String S1585 = "constant number 1585";
+    static final String S1586 = "constant number 1586";
+    static final String S1587 = "constant number 1587";
+    static final String S1588 = "constant number 1588";
+    static final String S1589 = "constant number 1589";
+    static final String S1590 = "constant number 1590";
+    static final String S1591 = "constant number 1591";
+    static final String S1592 = "constant number 1592";
+    static final String S1593 = "constant number 1593";
+    static final String S1594 = "constant number 1594";
+    static final String S1595 = "constant number 1595";
+    static final String S1596 = "constant number 1596";
+    static final String S1597 = "constant number 1597";
+    static final String S1598 = "constant number 1598";
+    static final String S1599 = "constant number 1599";
+    static final String S1600 = "constant number 1600";
+    static final String S1601 = "constant number 1601";
+    static final String S1602 = "constant number 1602";
+    static final String S1603 = "constant number 1603";
+    static final String S1604 = "constant number 1604";
+    static final String S1605 = "constant number 1605";
+    static final String S1606 = "constant number 1606";
+    static final String S1607 = "constant number 1607";
+    static final String S1608 = "constant number 1608";
+    static final String S1609 = "constant number 1609";
+    static final String S1610 = "constant number 1610";
+    static final String S1611 = "constant number 1611";
+    static final String S1612 = "constant number 1612";
+    static final String S1613 = "constant number 1613";
+    static final String S1614 = "constant number 1614";
+    static final String S1615 = "constant number 1615";
+    static final String S1616 = "constant number 1616";
+    static final String S1617 = "constant number 1617";
+    static final String S1618 = "constant number 1618";
+    static final String S1619 = "constant number 1619";
+    static final String S1620 = "constant number 1620";
+    static final String S1621 = "constant number 1621";
+    static final String S1622 = "constant number 1622";
+    static final String S1623 = "constant number 1623";
+    static final String S1624 = "constant number 1624";
+    static final String S1625 = "constant number 1625";
+    static final String S1626 = "constant number 1626";
+    static final String S1627 = "constant number 1627";
+    static final String S1628 = "constant number 1628";
+    static final String S1629 = "constant number 1629";
+    static final String S1630 = "constant number 1630";
+    static final String S1631 = "constant number 1631";
+    static final String S1632 = "constant number 1632";
+    static final String S1633 = "constant number 1633";
+    static final String S1634 = "constant number 1634";
+    static final String S1635 = "constant number 1635";
+    static final String S1636 = "constant number 1636";
+    static final String S1637 = "constant number 1637";
+    static final String S1638 = "constant number 1638";
+    static final String S1639 = "constant number 1639";
+    static final String S1640 = "constant number 1640";
+    static final String S1641 = "constant number 1641";
+    static final String S1642 = "constant number 1642";
+    static final String S1643 = "constant number 1643";
+    static final String S1644 = "constant number 1644";
+    static final String S1645 = "constant number 1645";
+    static final String S1646 = "constant number 1646";
+    static final String S1647 = "constant number 1647";
+    static final String S1648 = "constant number 1648";
+    static final String S1649 = "constant number 1649";
+    static final String S1650 = "constant number 1650";
+    static final String S1651 = "constant number 1651";
+    static final String S1652 = "constant number 1652";
+    static final String S1653 = "constant number 1653";
+    static final String S1654 = "constant number 1654";
+    static final String S1655 = "constant number 1655";
+    static final String S1656 = "constant number 1656";
+    static final String S1657 = "constant number 1657";
+    static final String S1658 = "constant number 1658";
+    static final String S1659 = "constant number 1659";
+    static final String S1660 = "constant number 1660";
+    static final String S1661 = "constant number 1661";
+    static final String S1662 = "constant number 1662";
+    static final String S1663 = "constant number 1663";
+    static final String S1664 = "constant number 1664";
+    static final String S1665 = "constant number 1665";
+    static final String S1666 = "constant number 1666";
+    static final String S1667 = "constant number 1667";
+    static final String S1668 = "constant number 1668";
+    static final String S1669 = "constant number 1669";
+    static final String S1670 = "constant number 1670";
+    static final String S1671 = "constant number 1671";
+    static final String S1672 = "constant number 1672";
+    static final String S1673 = "constant number 1673";
+    static final String S1674 = "constant number 1674";
+    static final String S1675 = "constant number 1675";
+    static final String S1676 = "constant number 1676";
+    static final String S1677 = "constant number 1677";
+    static final String S1678 = "constant number 1678";
+    static final String S1679 = "constant number 1679";
+    static final String S1680 = "constant number 1680";
+    static final String S1681 = "constant number 1681";
+    static final String S1682 = "constant number 1682";
+    static final String S1683 = "constant number 1683";
+    static final String S1684 = "constant number 1684";
+    static final String S1685 = "constant number 1685";
+    static final String S1686 = "constant number 1686";
+    static final String S1687 = "constant number 1687";
+    static final String S1688 = "constant number 1688";
+    static final String S1689 = "constant number 1689";
+    static final String S1690 = "constant number 1690";
+    static final String S1691 = "constant number 1691";
+    static final String S1692 = "constant number 1692";
+    static final String S1693 = "constant number 1693";
+    static final String S1694 = "constant number 1694";
+    static final String S1695 = "constant number 1695";
+    static final String S1696 = "constant number 1696";
+    static final String S1697 = "constant number 1697";
+    static final String S1698 = "constant number 1698";
+    static final String S1699 = "constant number 1699";
+    static final String S1700 = "constant number 1700";
+    static final String S1701 = "constant number 1701";
+    static final String S1702 = "constant number 1702";
+    static final String S1703 = "constant number 1703";
+    static final String S1704 = "constant number 1704";
+    static final String S1705 = "constant number 1705";
+    static final String S1706 = "constant number 1706";
+    static final String S1707 = "constant number 1707";
+    static final String S1708 = "constant number 1708";
+    static final String S1709 = "constant number 1709";
+    static final String S1710 = "constant number 1710";
+    static final String S1711 = "constant number 1711";
+    static final String S1712 = "constant number 1712";
+    static final String S1713 = "constant number 1713";
+    static final String S1714 = "constant number 1714";
+    static final String S1715 = "constant number 1715";
+    static final String S1716 = "constant number 1716";
+    static final String S1717 = "constant number 1717";
+    static final String S1718 = "constant number 1718";
+    static final String S1719 = "constant number 1719";
+    static final String S1720 = "constant number 1720";
+    static final String S1721 = "constant number 1721";
+    static final String S1722 = "constant number 1722";
+    static final String S1723 = "constant number 1723";
+    static final String S1724 = "constant number 1724";
+    static final String S1725 = "constant number 1725";
+    static final String S1726 = "constant number 1726";
+    static final String S1727 = "constant number 1727";
+    static final String S1728 = "constant number 1728";
+    static final String S1729 = "constant number 1729";
+    static final String S1730 = "constant number 1730";
+    static final String S1731 = "constant number 1731";
+    static final String S1732 = "constant number 1732";
+    static final String S1733 = "constant number 1733";
+    static final String S1734 = "constant number 1734";
+    static final String S1735 = "constant number 1735";
+    static final String S1736 = "constant number 1736";
+    static final String S1737 = "constant number 1737";
+    static final String S1738 = "constant number 1738";
+    static final String S1739 = "constant number 1739";
+    static final String S1740 = "constant number 1740";
+    static final String S1741 = "constant number 1741";
+    static final String S1742 = "constant number 1742";
+    static final String S1743 = "constant number 1743";
+    static final String S1744 = "constant number 1744";
+    static final String S1745 = "constant number 1745";
+    static final String S1746 = "constant number 1746";
+    static final String S1747 = "constant number 1747";
+    static final String S1748 = "constant number 1748";
+    static final String S1749 = "constant number 1749";
+    static final String S1750 = "constant number 1750";
+    static final String S1751 = "constant number 1751";
+    static final String S1752 = "constant number 1752";
+    static final String S1753 = "constant number 1753";
+    static final String S1754 = "constant number 1754";
+    static final String S1755 = "constant number 1755";
+    static final String S1756 = "constant number 1756";
+    static final String S1757 = "constant number 1757";
+    static final String S1758 = "constant number 1758";
+    static final String S1759 = "constant number 1759";
+    static final String S1760 = "constant number 1760";
+    static final String S1761 = "constant number 1761";
+    static final String S1762 = "constant number 1762";
+    static final String S1763 = "constant number 1763";
+    static final String S1764 = "constant number 1764";
+    static final String S1765 = "constant number 1765";
+    static final String S1766 = "constant number 1766";
+    static final String S1767 = "constant number 1767";
+    static final String S1768 = "constant number 1768";
+    static final String S1769 = "constant number 1769";
+    static final String S1770 = "constant number 1770";
+    static final String S1771 = "constant number 1771";
+    static final String S1772 = "constant number 1772";
+    static final String S1773 = "constant number 1773";
+    static final String S1774 = "constant number 1774";
+    static final String S1775 = "constant number 1775";
+    static final String S1776 = "constant number 1776";
+    static final String S1777 = "constant number 1777";
+    static final String S1778 = "constant number 1778";
+    static final String S1779 = "constant number 1779";
+    static final String S1780 = "constant number 1780";
+    static final String S1781 = "constant number 1781";
+    static final String S1782 = "constant number 1782";
+    static final String S1783 = "constant number 1783";
+    static final String S1784 = "constant number 1784";
+    static final String S1785 = "constant number 1785";
+    static final String S1786 = "constant number 1786";
+    static final String S1787 = "constant number 1787";
+    static final String S1788 = "constant number 1788";
+    static final String S1789 = "constant number 1789";
+    static final String S1790 = "constant number 1790";
+    static final String S1791 = "constant number 1791";
+    static final String S1792 = "constant number 1792";
+    static final String S1793 = "constant number 1793";
+    static final String S1794 = "constant number 1794";
+    static final String S1795 = "constant number 1795";
+    static final String S1796 = "constant number 1796";
+    static final String S1797 = "constant number 1797";
+    static final String S1798 = "constant number 1798";
+    static final String S1799 = "constant number 1799";
+    static final String S1800 = "constant number 1800";
+    static final String S1801 = "constant number 1801";
+    static final String S1802 = "constant number 1802";
+    static final String S1803 = "constant number 1803";
+    static final String S1804 = "constant number 1804";
+    static final String S1805 = "constant number 1805";
+    static final String S1806 = "constant number 1806";
+    static final String S1807 = "constant number 1807";
+    static final String S1808 = "constant number 1808";
+    static final String S1809 = "constant number 1809";
+    static final String S1810 = "constant number 1810";
+    static final String S1811 = "constant number 1811";
+    static final String S1812 = "constant number 1812";
+    static final String S1813 = "constant number 1813";
+    static final String S1814 = "constant number 1814";
+    static final String S1815 = "constant number 1815";
+    static final String S1816 = "constant number 1816";
+    static final String S1817 = "constant number 1817";
+    static final String S1818 = "constant number 1818";
+    static final String S1819 = "constant number 1819";
+    static final String S1820 = "constant number 1820";
+    static final String S1821 = "constant number 1821";
+    static final String S1822 = "constant number 1822";
+    static final String S1823 = "constant number 1823";
+    static final String S1824 = "constant number 1824";
+    static final String S1825 = "constant number 1825";
+    static final String S1826 = "constant number 1826";
+    static final String S1827 = "constant number 1827";
+    static final String S1828 = "constant number 1828";
+    static final String S1829 = "constant number 1829";
+    static final String S1830 = "constant number 1830";
+    static final String S1831 = "constant number 1831";
+    static final String S1832 = "constant number 1832";
+    static final String S1833 = "constant number 1833";
+    static final String S1834 = "constant number 1834";
+    static final String S1835 = "constant number 1835";
+    static final String S1836 = "constant number 1836";
+    static final String S1837 = "constant number 1837";
+    static final String S1838 = "constant number 1838";
+    static final String S1839 = "constant number 1839";
+    static final String S1840 = "constant number 1840";
+    static final String S1841 = "constant number 1841";
+    static final String S1842 = "constant number 1842";
+    static final String S1843 = "constant number 1843";
+    static final String S1844 = "constant number 1844";
+    static final String S1845 = "constant number 1845";
+    static final String S1846 = "constant number 1846";
+    static final String S1847 = "constant number 1847";
+    static final String S1848 = "constant number 1848";
+    static final String S1849 = "constant number 1849";
+    static final String S1850 = "constant number 1850";
+    static final String S1851 = "constant number 1851";
+    static final String S1852 = "constant number 1852";
+    static final String S1853 = "constant number 1853";
+    static final String S1854 = "constant number 1854";
+    static final String S1855 = "constant number 1855";
+    static final String S1856 = "constant number 1856";
+    static final String S1857 = "constant number 1857";
+    static final String S1858 = "constant number 1858";
+    static final String S1859 = "constant number 1859";
+    static final String S1860 = "constant number 1860";
+    static final String S1861 = "constant number 1861";
+    static final String S1862 = "constant number 1862";
+    static final String S1863 = "constant number 1863";
+    static final String S1864 = "constant number 1864";
+    static final String S1865 = "constant number 1865";
+    static final String S1866 = "constant number 1866";
+    static final String S1867 = "constant number 1867";
+    static final String S1868 = "constant number 1868";
+    static final String S1869 = "constant number 1869";
+    static final String S1870 = "constant number 1870";
+    static final String S1871 = "constant number 1871";
+    static final String S1872 = "constant number 1872";
+    static final String S1873 = "constant number 1873";
+    static final String S1874 = "constant number 1874";
+    static final String S1875 = "constant number 1875";
+    static final String S1876 = "constant number 1876";
+    static final String S1877 = "constant number 1877";
+    static final String S1878 = "constant number 1878";
+    static final String S1879 = "constant number 1879";
+    static final String S1880 = "constant number 1880";
+    static final String S1881 = "constant number 1881";
+    static final String S1882 = "constant number 1882";
+    static final String S1883 = "constant number 1883";
+    static final String S1884 = "constant number 1884";
+    static final String S1885 = "constant number 1885";
+    static final String S1886 = "constant number 1886";
+    static final String S1887 = "constant number 1887";
+    static final String S1888 = "constant number 1888";
+    static final String S1889 = "constant number 1889";
+    static final String S1890 = "constant number 1890";
+    static final String S1891 = "constant number 1891";
+    static final String S1892 = "constant number 1892";
+    static final String S1893 = "constant number 1893";
+    static final String S1894 = "constant number 1894";
+    static final String S1895 = "constant number 1895";
+    static final String S1896 = "constant number 1896";
+    static final String S1897 = "constant number 1897";
+    static final String S1898 = "constant number 1898";
+    static final String S1899 = "constant number 1899";
+    static final String S1900 = "constant number 1900";
+    static final String S1901 = "constant number 1901";
+    static final String S1902 = "constant number 1902";
+    static final String S1903 = "constant number 1903";
+    static final String S1904 = "constant number 1904";
+    static final String S1905 = "constant number 1905";
+    static final String S1906 = "constant number 1906";
+    static final String S1907 = "constant number 1907";
+    static final String S1908 = "constant number 1908";
+    static final String S1909 = "constant number 1909";
+    static final String S1910 = "constant number 1910";
+    static final String S1911 = "constant number 1911";
+    static final String S1912 = "constant number 1912";
+    static final String S1913 = "constant number 1913";
+    static final String S1914 = "constant number 1914";
+    static final String S1915 = "constant number 1915";
+    static final String S1916 = "constant number 1916";
+    static final String S1917 = "constant number 1917";
+    static final String S1918 = "constant number 1918";
+    static final String S1919 = "constant number 1919";
+    static final String S1920 = "constant number 1920";
+    static final String S1921 = "constant number 1921";
+    static final String S1922 = "constant number 1922";
+    static final String S1923 = "constant number 1923";
+    static final String S1924 = "constant number 1924";
+    static final String S1925 = "constant number 1925";
+    static final String S1926 = "constant number 1926";
+    static final String S1927 = "constant number 1927";
+    static final String S1928 = "constant number 1928";
+    static final String S1929 = "constant number 1929";
+    static final String S1930 = "constant number 1930";
+    static final String S1931 = "constant number 1931";
+    static final String S1932 = "constant number 1932";
+    static final String S1933 = "constant number 1933";
+    static final String S1934 = "constant number 1934";
+    static final String S1935 = "constant number 1935";
+    static final String S1936 = "constant number 1936";
+    static final String S1937 = "constant number 1937";
+    static final String S1938 = "constant number 1938";
+    static final String S1939 = "constant number 1939";
+    static final String S1940 = "constant number 1940";
+    static final String S1941 = "constant number 1941";
+    static final String S1942 = "constant number 1942";
+    static final String S1943 = "constant number 1943";
+    static final String S1944 = "constant number 1944";
+    static final String S1945 = "constant number 1945";
+    static final String S1946 = "constant number 1946";
+    static final String S1947 = "constant number 1947";
+    static final String S1948 = "constant number 1948";
+    static final String S1949 = "constant number 1949";
+    static final String S1950 = "constant number 1950";
+    static final String S1951 = "constant number 1951";
+    static final String S1952 = "constant number 1952";
+    static final String S1953 = "constant number 1953";
+    static final String S1954 = "constant number 1954";
+    static final String S1955 = "constant number 1955";
+    static final String S1956 = "constant number 1956";
+    static final String S1957 = "constant number 1957";
+    static final String S1958 = "constant number 1958";
+    static final String S1959 = "constant number 1959";
+    static final String S1960 = "constant number 1960";
+    static final String S1961 = "constant number 1961";
+    static final String S1962 = "constant number 1962";
+    static final String S1963 = "constant number 1963";
+    static final String S1964 = "constant number 1964";
+    static final String S1965 = "constant number 1965";
+    static final String S1966 = "constant number 1966";
+    static final String S1967 = "constant number 1967";
+    static final String S1968 = "constant number 1968";
+    static final String S1969 = "constant number 1969";
+    static final String S1970 = "constant number 1970";
+    static final String S1971 = "constant number 1971";
+    static final String S1972 = "constant number 1972";
+    static final String S1973 = "constant number 1973";
+    static final String S1974 = "constant number 1974";
+    static final String S1975 = "constant number 1975";
+    static final String S1976 = "constant number 1976";
+    static final String S1977 = "constant number 1977";
+    static final String S1978 = "constant number 1978";
+    static final String S1979 = "constant number 1979";
+    static final String S1980 = "constant number 1980";
+    static final String S1981 = "constant number 1981";
+    static final String S1982 = "constant number 1982";
+    static final String S1983 = "constant number 1983";
+    static final String S1984 = "constant number 1984";
+    static final String S1985 = "constant number 1985";
+    static final String S1986 = "constant number 1986";
+    static final String S1987 = "constant number 1987";
+    static final String S1988 = "constant number 1988";
+    static final String S1989 = "constant number 1989";
+    static final String S1990 = "constant number 1990";
+    static final String S1991 = "constant number 1991";
+    static final String S1992 = "constant number 1992";
+    static final String S1993 = "constant number 1993";
+    static final String S1994 = "constant number 1994";
+    static final String S1995 = "constant number 1995";
+    static final String S1996 = "constant number 1996";
+    static final String S1997 = "constant number 1997";
+    static final String S1998 = "constant number 1998";
+    static final String S1999 = "constant number 1999";
+}
@@ -0,0 +1,12 @@
+// Compiled with: javac -encoding UTF-8 Legacy.java
+public class Legacy {
+    @Deprecated
+    int count;
+
+    int total;
+
+    @Deprecated
+    void oldMethod() {}
+
+    void newMethod() {}
+}
@@ -0,0 +1,10 @@
+// Compiled with: javac -encoding UTF-8 -g Locals.java
+public class Locals {
+    public static void main(String[] args) {
+        int count = args.length;
+        for (int i = 0; i < count; i++) {
+            String arg = args[i];
+            System.out.println(arg);
+        }
+    }
+}
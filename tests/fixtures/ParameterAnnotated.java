@@ -0,0 +1,18 @@
+// Compiled with: javac -encoding UTF-8 ParameterAnnotated.java
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+
+public class ParameterAnnotated {
+    @Retention(RetentionPolicy.RUNTIME)
+    @interface Named {
+        String value();
+    }
+
+    // Annotations without @Retention are kept in the class file but invisible at runtime.
+    @interface Checked {
+    }
+
+    @Checked
+    void greet(@Named("who") String name, int times, @Checked String suffix) {
+    }
+}
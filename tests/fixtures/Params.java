@@ -0,0 +1,6 @@
+// Compiled with: javac -encoding UTF-8 -parameters Params.java
+public class Params {
+    static int add(int first, final int second) {
+        return first + second;
+    }
+}
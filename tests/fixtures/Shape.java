@@ -0,0 +1,6 @@
+// Compiled with: javac -encoding UTF-8 Shape.java
+public sealed interface Shape permits Shape.Circle, Shape.Square {
+    record Circle(double radius) implements Shape {}
+
+    record Square(double side) implements Shape {}
+}
@@ -0,0 +1,11 @@
+// Compiled with: javac -encoding UTF-8 Throws.java
+import java.io.IOException;
+
+public class Throws {
+    void read() throws IOException {
+        throw new IOException("not today");
+    }
+
+    void quiet() {
+    }
+}